@@ -0,0 +1,73 @@
+use git2::Repository;
+use std::error::Error;
+use std::path::Path;
+use std::time::Duration;
+
+/// Open a git repository on a blocking thread and give up after the given timeout.
+///
+/// Opening very large repositories or repositories on network drives can take a long time.
+/// This function keeps the async runtime responsive meanwhile. The opening itself can't be
+/// cancelled, so after a timeout the blocking thread finishes in the background.
+///
+/// ## Returns
+///
+/// The opened [`Repository`] or error if the repository can't be opened in time.
+///
+/// ## Example
+/// ```
+/// use cargo_semantic_release::{open_repo_with_timeout, Changes};
+/// use std::time::Duration;
+///
+/// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+/// let git_repo = open_repo_with_timeout(".", Duration::from_secs(10))
+///     .await
+///     .expect("error during opening repository");
+/// let changes = Changes::from_repo(&git_repo).expect("error during fetching changes");
+/// println!("changes: {changes}")
+/// # })
+/// ```
+pub async fn open_repo_with_timeout(
+    path: impl AsRef<Path>,
+    timeout: Duration,
+) -> Result<Repository, Box<dyn Error + Send + Sync>> {
+    let path = path.as_ref().to_path_buf();
+    let open_task = tokio::task::spawn_blocking(move || Repository::open(path));
+
+    match tokio::time::timeout(timeout, open_task).await {
+        Ok(join_result) => Ok(join_result??),
+        Err(_) => Err(format!("opening repository timed out after {timeout:?}").into()),
+    }
+}
+
+#[cfg(test)]
+mod async_repo_tests {
+    use crate::async_repo::open_repo_with_timeout;
+    use crate::test_util::repo_init;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn opening_repo_within_timeout() {
+        // Given
+        let (temp_dir, repository) = repo_init(Some(vec!["initial commit"]));
+
+        // When
+        let result = open_repo_with_timeout(temp_dir.path(), Duration::from_secs(10))
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(result.path(), repository.path());
+    }
+
+    #[tokio::test]
+    async fn opening_missing_repo() {
+        // Given
+        let temp_dir = tempfile::TempDir::new().unwrap();
+
+        // When
+        let result = open_repo_with_timeout(temp_dir.path(), Duration::from_secs(10)).await;
+
+        // Then
+        assert!(result.is_err(), "Expected an error, but got Ok");
+    }
+}
@@ -1,12 +1,21 @@
-use crate::repo::ConventionalCommit;
+use crate::intention::{documentation_gitmojis, Category, Classify, Gitmoji, IntentionMap};
 pub use crate::repo::RepositoryExtension;
-use git2::Repository;
-use std::collections::HashSet;
+use crate::repo::{ConventionalCommit, FetchOptions, VersionTag, SHORT_HASH_LEN};
+use crate::report::Report;
+use git2::{ErrorCode, Repository};
+use semver::{BuildMetadata, Prerelease, Version};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::error::Error;
 use std::fmt::Display;
+use std::str::FromStr;
+
+/// Version tag of a release, or `None` for the unreleased changes, with the changes of the
+/// release. See [`Changes::history`].
+pub type Release = (Option<VersionTag>, Changes);
 
 /// Structure that represents the changes in a git repository
-#[derive(Debug)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, schemars::JsonSchema))]
 pub struct Changes {
     /// Vector of commits with major changes
     major: Vec<ConventionalCommit>,
@@ -16,6 +25,12 @@ pub struct Changes {
     patch: Vec<ConventionalCommit>,
     /// Vector of commits with other changes
     other: Vec<ConventionalCommit>,
+    /// Number of commits that were analyzed, including the ones without a known intention
+    #[cfg_attr(feature = "serde", serde(skip))]
+    analyzed: usize,
+    /// Vector of commits without a known intention
+    #[cfg_attr(feature = "serde", serde(skip))]
+    unrecognized: Vec<ConventionalCommit>,
 }
 
 impl Changes {
@@ -40,620 +55,3004 @@ impl Changes {
     /// println!("changes: {changes}")
     /// ```
     pub fn from_repo(repository: &impl RepositoryExtension) -> Result<Self, Box<dyn Error>> {
-        let major_intentions = [(":boom:", "💥")];
-        let minor_intentions = [
-            (":sparkles:", "✨"),
-            (":children_crossing:", "🚸"),
-            (":lipstick:", "💄"),
-            (":iphone:", "📱"),
-            (":egg:", "🥚"),
-            (":chart_with_upwards_trend:", "📈"),
-            (":heavy_plus_sign:", "➕"),
-            (":heavy_minus_sign:", "➖"),
-            (":passport_control:", "🛂"),
-        ];
-        let patch_intentions = [
-            (":art:", "🎨"),
-            (":ambulance:", "🚑️"),
-            (":lock:", "🔒️"),
-            (":bug:", "🐛"),
-            (":zap:", "⚡️"),
-            (":goal_net:", "🥅"),
-            (":alien:", "👽️"),
-            (":wheelchair:", "♿️"),
-            (":speech_balloon:", "💬"),
-            (":mag:", "🔍️"),
-            (":fire:", "🔥"),
-            (":white_check_mark:", "✅"),
-            (":closed_lock_with_key:", "🔐"),
-            (":rotating_light:", "🚨"),
-            (":green_heart:", "💚"),
-            (":arrow_down:", "⬇️"),
-            (":arrow_up:", "⬆️"),
-            (":pushpin:", "📌"),
-            (":construction_worker:", "👷"),
-            (":recycle:", "♻️"),
-            (":wrench:", "🔧"),
-            (":hammer:", "🔨"),
-            (":globe_with_meridians:", "🌐"),
-            (":package:", "📦️"),
-            (":truck:", "🚚"),
-            (":bento:", "🍱"),
-            (":card_file_box:", "🗃️"),
-            (":loud_sound:", "🔊"),
-            (":mute:", "🔇"),
-            (":building_construction:", "🏗️"),
-            (":camera_flash:", "📸"),
-            (":label:", "🏷️"),
-            (":seedling:", "🌱"),
-            (":triangular_flag_on_post:", "🚩"),
-            (":dizzy:", "💫"),
-            (":adhesive_bandage:", "🩹"),
-            (":monocle_face:", "🧐"),
-            (":necktie:", "👔"),
-            (":stethoscope:", "🩺"),
-            (":technologist:", "🧑‍💻"),
-            (":thread:", "🧵"),
-            (":safety_vest:", "🦺"),
-        ];
-        let other_intentions = [
-            (":memo:", "📝"),
-            (":rocket:", "🚀"),
-            (":tada:", "🎉"),
-            (":bookmark:", "🔖"),
-            (":construction:", "🚧"),
-            (":pencil2:", "✏️"),
-            (":poop:", "💩"),
-            (":rewind:", "⏪️"),
-            (":twisted_rightwards_arrows:", "🔀"),
-            (":page_facing_up:", "📄"),
-            (":bulb:", "💡"),
-            (":beers:", "🍻"),
-            (":bust_in_silhouette:", "👥"),
-            (":clown_face:", "🤡"),
-            (":see_no_evil:", "🙈"),
-            (":alembic:", "⚗️"),
-            (":wastebasket:", "🗑️"),
-            (":coffin:", "⚰️"),
-            (":test_tube:", "🧪"),
-            (":bricks:", "🧱"),
-            (":money_with_wings:", "💸"),
-        ];
-
-        let version_tag = repository.get_latest_version_tag()?;
+        Self::from_repo_with_options(repository, &FetchOptions::default())
+    }
 
-        let unsorted_commits = match version_tag {
-            Some(version_tag) => repository.fetch_commits_until(version_tag.commit_oid),
-            None => repository.fetch_all_commits(),
+    /// Sort the commits from a given repo into change categories like [`Changes::from_repo`],
+    /// but only consider the latest `max_commits` commits.
+    ///
+    /// This avoids walking the whole history of huge repositories without version tags.
+    ///
+    /// ## Returns
+    ///
+    /// The [`Changes`] structure with the sorted commits or error type.
+    ///
+    /// ## Example
+    /// ```
+    /// use git2::Repository;
+    /// use cargo_semantic_release::Changes;
+    ///
+    /// let git_repo = Repository::open(".").unwrap();
+    ///
+    /// let changes = Changes::from_repo_limited(&git_repo, 100)
+    ///     .expect("error during fetching changes");
+    /// println!("changes: {changes}")
+    /// ```
+    pub fn from_repo_limited(
+        repository: &impl RepositoryExtension,
+        max_commits: usize,
+    ) -> Result<Self, Box<dyn Error>> {
+        let options = FetchOptions {
+            max_commits: Some(max_commits),
+            ..Default::default()
         };
-
-        match unsorted_commits {
-            Ok(unsorted_commits) => Ok(Self {
-                major: get_commits_with_intention(
-                    unsorted_commits.clone(),
-                    major_intentions.to_vec(),
-                ),
-                minor: get_commits_with_intention(
-                    unsorted_commits.clone(),
-                    minor_intentions.to_vec(),
-                ),
-                patch: get_commits_with_intention(
-                    unsorted_commits.clone(),
-                    patch_intentions.to_vec(),
-                ),
-                other: get_commits_with_intention(unsorted_commits, other_intentions.to_vec()),
-            }),
-            Err(e) => Err(e),
-        }
+        Self::from_repo_with_options(repository, &options)
     }
 
-    /// Evaluate the changes find in a repository to figure out the semantic version action
+    /// Sort the commits from a given repo into change categories like [`Changes::from_repo`],
+    /// but fetch the commits according to the given [`FetchOptions`].
     ///
     /// ## Returns
     ///
-    /// [`SemanticVersionAction`] enum for the suggested semantic version change.
+    /// The [`Changes`] structure with the sorted commits or error type.
     ///
     /// ## Example
-    ///
     /// ```
-    ///  use git2::Repository;
-    ///  use cargo_semantic_release::Changes;
+    /// use git2::Repository;
+    /// use cargo_semantic_release::{Changes, FetchOptions};
     ///
-    ///  let git_repo = Repository::open(".").unwrap();
+    /// let git_repo = Repository::open(".").unwrap();
+    /// let options = FetchOptions {
+    ///     reference: Some("HEAD".to_string()),
+    ///     ..Default::default()
+    /// };
     ///
-    ///  let action = Changes::from_repo(&git_repo).expect("Error during fetching changes").define_action_for_semantic_version();
-    ///  println!("suggested change of semantic version: {}", action);
+    /// let changes = Changes::from_repo_with_options(&git_repo, &options)
+    ///     .expect("error during fetching changes");
+    /// println!("changes: {changes}")
     /// ```
-    pub fn define_action_for_semantic_version(self) -> SemanticVersionAction {
-        if !self.major.is_empty() {
-            return SemanticVersionAction::IncrementMajor;
-        }
-        if !self.minor.is_empty() {
-            return SemanticVersionAction::IncrementMinor;
-        }
-        if !self.patch.is_empty() {
-            return SemanticVersionAction::IncrementPatch;
-        }
-        SemanticVersionAction::Keep
+    pub fn from_repo_with_options(
+        repository: &impl RepositoryExtension,
+        options: &FetchOptions,
+    ) -> Result<Self, Box<dyn Error>> {
+        Self::from_repo_with_intentions(repository, options, &IntentionMap::default())
     }
-}
-
-impl TryFrom<&Repository> for Changes {
-    type Error = Box<dyn Error>;
 
-    fn try_from(value: &Repository) -> Result<Self, Self::Error> {
-        Self::from_repo(value)
+    /// Sort the commits from a given repo into change categories like
+    /// [`Changes::from_repo_with_options`], but classify the commits according to the
+    /// given [`IntentionMap`], e.g. one with custom intentions.
+    ///
+    /// ## Returns
+    ///
+    /// The [`Changes`] structure with the sorted commits or error type.
+    ///
+    /// ## Example
+    /// ```
+    /// use git2::Repository;
+    /// use cargo_semantic_release::{Category, Changes, FetchOptions, IntentionMap};
+    ///
+    /// let git_repo = Repository::open(".").unwrap();
+    /// let mut intention_map = IntentionMap::default();
+    /// intention_map
+    ///     .register(":sparkling_heart:", "💖", Category::Minor)
+    ///     .unwrap();
+    ///
+    /// let changes =
+    ///     Changes::from_repo_with_intentions(&git_repo, &FetchOptions::default(), &intention_map)
+    ///         .expect("error during fetching changes");
+    /// println!("changes: {changes}")
+    /// ```
+    pub fn from_repo_with_intentions(
+        repository: &impl RepositoryExtension,
+        options: &FetchOptions,
+        intention_map: &IntentionMap,
+    ) -> Result<Self, Box<dyn Error>> {
+        let unsorted_commits = fetch_commits_since_latest_tag(repository, options)?;
+        Ok(Self::from_commits_with_intentions(
+            unsorted_commits,
+            intention_map,
+        ))
     }
-}
 
-impl PartialEq for Changes {
-    /// Compare two [`Changes`] struct to see if they have the same elements.
-    ///
-    /// # Returns
+    /// Sort the commits from a given repo into change categories like [`Changes::from_repo`],
+    /// but call `on_commit` with each commit as it's classified, e.g. for reporting the
+    /// progress of analyzing a large repository.
     ///
-    /// `true` if the two structure has the same elements regardless they order, `false` otherwise.
+    /// ## Returns
     ///
-    /// # Example
+    /// The [`Changes`] structure with the sorted commits or error type.
     ///
+    /// ## Example
     /// ```
-    /// use git2::AttrValue::True;
     /// use git2::Repository;
     /// use cargo_semantic_release::Changes;
     ///
     /// let git_repo = Repository::open(".").unwrap();
     ///
-    /// let changes_1 = Changes::from_repo(&git_repo).expect("error during fetching changes");
-    /// let changes_2 = Changes::from_repo(&git_repo).expect("error during fetching changes");
+    /// let mut analyzed = 0;
+    /// let changes = Changes::from_repo_with(&git_repo, |_commit| analyzed += 1)
+    ///     .expect("error during fetching changes");
+    /// println!("analyzed {analyzed} commits: {changes}")
+    /// ```
+    pub fn from_repo_with(
+        repository: &impl RepositoryExtension,
+        mut on_commit: impl FnMut(&ConventionalCommit),
+    ) -> Result<Self, Box<dyn Error>> {
+        let intention_map = IntentionMap::default();
+        let mut changes = Self::default();
+        for commit in fetch_commits_since_latest_tag(repository, &FetchOptions::default())? {
+            on_commit(&commit);
+            changes.append_commit_with_intentions(commit, &intention_map);
+        }
+        Ok(changes)
+    }
+
+    /// Sort the commits from a given repo and from its submodules into change categories
+    /// like [`Changes::from_repo_with_intentions`].
     ///
-    /// assert_eq!(changes_1, changes_2);
+    /// The submodules are analyzed recursively with the default [`FetchOptions`] and their
+    /// changes are merged into the changes of the repository. Submodules that are not
+    /// checked out are skipped. The hashes of the submodule commits are namespaced with the
+    /// path of the submodule, e.g. `libs/core:3c8e19b`, to distinguish them from the commits
+    /// of the repository.
+    ///
+    /// ## Returns
+    ///
+    /// The [`Changes`] structure with the sorted commits or error type.
+    ///
+    /// ## Example
     /// ```
-    fn eq(&self, other: &Self) -> bool {
-        self.major.iter().collect::<HashSet<_>>() == other.major.iter().collect::<HashSet<_>>()
-            && self.minor.iter().collect::<HashSet<_>>()
-                == other.minor.iter().collect::<HashSet<_>>()
-            && self.patch.iter().collect::<HashSet<_>>()
-                == other.patch.iter().collect::<HashSet<_>>()
-            && self.other.iter().collect::<HashSet<_>>()
-                == other.other.iter().collect::<HashSet<_>>()
+    /// use git2::Repository;
+    /// use cargo_semantic_release::{Changes, FetchOptions, IntentionMap};
+    ///
+    /// let git_repo = Repository::open(".").unwrap();
+    ///
+    /// let changes = Changes::from_repo_recursive(
+    ///     &git_repo,
+    ///     &FetchOptions::default(),
+    ///     &IntentionMap::default(),
+    /// )
+    /// .expect("error during fetching changes");
+    /// println!("changes: {changes}")
+    /// ```
+    pub fn from_repo_recursive(
+        repository: &Repository,
+        options: &FetchOptions,
+        intention_map: &IntentionMap,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut changes = Self::from_repo_with_intentions(repository, options, intention_map)?;
+        for submodule in repository.submodules()? {
+            let Ok(submodule_repository) = submodule.open() else {
+                continue;
+            };
+            let mut submodule_changes = Self::from_repo_recursive(
+                &submodule_repository,
+                &FetchOptions::default(),
+                intention_map,
+            )?;
+            submodule_changes.namespace_hashes(&submodule.path().to_string_lossy());
+            changes.merge(submodule_changes);
+        }
+        Ok(changes)
     }
-}
 
-impl Display for Changes {
-    /// Format the values in [`Changes`]
+    /// Sort the commits of a two-dot revspec like `v1.0.0..HEAD` into change categories,
+    /// instead of the commits since the latest version tag.
     ///
-    /// Example output:
-    /// ```shell
-    ///major:
-    ///         :boom: Introduce breaking change
+    /// Only the commits are taken from the range: [`FetchOptions::reference`] is ignored and
+    /// three-dot ranges are rejected.
     ///
-    /// minor:
-    ///         :sparkles: Add new feature
+    /// ## Returns
     ///
-    /// patch:
-    ///         :recycle: Refactor codebase
+    /// The [`Changes`] structure with the sorted commits or error type.
     ///
-    /// other:
-    ///         :bulb: Add comments
+    /// ## Example
     /// ```
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let major_changes = convert_to_string_vector(self.major.clone());
-        let minor_changes = convert_to_string_vector(self.minor.clone());
-        let patch_changes = convert_to_string_vector(self.patch.clone());
-        let other_changes = convert_to_string_vector(self.other.clone());
-        write!(
-            f,
-            "major:\n\t{}\nminor:\n\t{}\npatch:\n\t{}\nother:\n\t{}",
-            major_changes.join("\t"),
-            minor_changes.join("\t"),
-            patch_changes.join("\t"),
-            other_changes.join("\t")
-        )
+    /// use git2::Repository;
+    /// use cargo_semantic_release::{Changes, FetchOptions, IntentionMap};
+    ///
+    /// let git_repo = Repository::open(".").unwrap();
+    ///
+    /// let changes = Changes::from_repo_range(
+    ///     &git_repo,
+    ///     "HEAD~1..HEAD",
+    ///     &FetchOptions::default(),
+    ///     &IntentionMap::default(),
+    /// )
+    /// .expect("error during fetching changes");
+    /// println!("changes: {changes}")
+    /// ```
+    pub fn from_repo_range(
+        repository: &impl RepositoryExtension,
+        range: &str,
+        options: &FetchOptions,
+        intention_map: &IntentionMap,
+    ) -> Result<Self, Box<dyn Error>> {
+        let commits = repository.fetch_commits_in_range(range, options)?;
+        Ok(Self::from_commits_with_intentions(commits, intention_map))
     }
-}
 
-/// Enum to represent the action for semantic version
-#[derive(PartialEq, Debug)]
-pub enum SemanticVersionAction {
-    IncrementMajor,
-    IncrementMinor,
-    IncrementPatch,
-    Keep,
-}
+    /// Sort the commits of every release into change categories, e.g. for generating a
+    /// complete changelog.
+    ///
+    /// The version tags are the ones of `options.scheme`. The commits reachable from a version
+    /// tag but not from the previous one belong to the release of that tag, and the first
+    /// release contains every commit up to the first tag. The commits after the latest version
+    /// tag are returned without a tag, as the unreleased changes.
+    ///
+    /// ## Returns
+    ///
+    /// The version tag and the [`Changes`] of each release, from the newest to the oldest,
+    /// starting with the unreleased changes, or error type.
+    ///
+    /// ## Example
+    /// ```
+    /// use git2::Repository;
+    /// use cargo_semantic_release::{Changes, FetchOptions, IntentionMap};
+    ///
+    /// let git_repo = Repository::open(".").unwrap();
+    ///
+    /// let history = Changes::history(&git_repo, &FetchOptions::default(), &IntentionMap::default())
+    ///     .expect("error during fetching changes");
+    /// for (version_tag, changes) in history {
+    ///     let version = version_tag.map_or("unreleased".to_string(), |tag| tag.version.to_string());
+    ///     println!("{version}: {}", changes.counts());
+    /// }
+    /// ```
+    pub fn history(
+        repository: &impl RepositoryExtension,
+        options: &FetchOptions,
+        intention_map: &IntentionMap,
+    ) -> Result<Vec<Release>, Box<dyn Error>> {
+        let version_tags =
+            repository.all_version_tags_with_scheme(&options.tag_prefixes, options.scheme)?;
+        let head = options.reference.as_deref().unwrap_or("HEAD");
 
-impl Display for SemanticVersionAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let msg = match self {
-            SemanticVersionAction::IncrementMajor => "increment major version",
-            SemanticVersionAction::IncrementMinor => "increment minor version",
-            SemanticVersionAction::IncrementPatch => "increment patch version",
-            SemanticVersionAction::Keep => "keep version",
+        let unreleased_commits = match version_tags.last() {
+            Some(latest_tag) => repository
+                .fetch_commits_in_range(&format!("{}..{head}", latest_tag.commit_oid), options)?,
+            None => repository.fetch_all_commits(options)?,
         };
-        write!(f, "{}", msg)
+        let mut history = vec![(
+            None,
+            Self::from_commits_with_intentions(unreleased_commits, intention_map),
+        )];
+
+        let mut previous_tag: Option<&VersionTag> = None;
+        let mut releases = Vec::new();
+        for version_tag in &version_tags {
+            let commits = match previous_tag {
+                Some(previous_tag) => repository.fetch_commits_in_range(
+                    &format!("{}..{}", previous_tag.commit_oid, version_tag.commit_oid),
+                    options,
+                )?,
+                None => repository.fetch_all_commits(&FetchOptions {
+                    reference: Some(version_tag.commit_oid.to_string()),
+                    ..options.clone()
+                })?,
+            };
+            releases.push((
+                Some(version_tag.clone()),
+                Self::from_commits_with_intentions(commits, intention_map),
+            ));
+            previous_tag = Some(version_tag);
+        }
+        history.extend(releases.into_iter().rev());
+        Ok(history)
     }
-}
 
-fn convert_to_string_vector(commits: Vec<ConventionalCommit>) -> Vec<String> {
-    commits
-        .into_iter()
-        .map(|commit| format!("{commit}"))
-        .collect::<Vec<String>>()
-}
+    /// Sort commit messages into change categories according to their commit intentions,
+    /// e.g. messages taken from the output of `git log`.
+    ///
+    /// Blank messages and messages without a known intention are ignored. The commits
+    /// created from the messages don't have a hash or an author.
+    ///
+    /// ## Returns
+    ///
+    /// The [`Changes`] structure with the sorted commits.
+    ///
+    /// ## Example
+    /// ```
+    /// use cargo_semantic_release::{Changes, SemanticVersionAction};
+    ///
+    /// let messages = vec![":sparkles: add feature".to_string(), "".to_string()];
+    ///
+    /// let action = Changes::from_messages(messages).define_action_for_semantic_version();
+    /// assert_eq!(action, SemanticVersionAction::IncrementMinor);
+    /// ```
+    pub fn from_messages(messages: Vec<String>) -> Self {
+        Self::from_messages_with_intentions(messages, &IntentionMap::default())
+    }
 
-fn get_commits_with_intention(
-    commits: Vec<ConventionalCommit>,
-    intentions: Vec<(&str, &str)>,
-) -> Vec<ConventionalCommit> {
-    commits
-        .into_iter()
-        .filter(|commit| {
-            intentions.iter().any(|intention| {
-                commit.message.contains(intention.0) || commit.message.contains(intention.1)
+    /// Sort commit messages into change categories like [`Changes::from_messages`], but
+    /// according to the given [`IntentionMap`].
+    pub fn from_messages_with_intentions(
+        messages: Vec<String>,
+        intention_map: &IntentionMap,
+    ) -> Self {
+        let commits = messages
+            .into_iter()
+            .filter(|message| !message.trim().is_empty())
+            .map(|message| ConventionalCommit {
+                message,
+                ..Default::default()
             })
-        })
-        .collect()
-}
+            .collect();
+        Self::from_commits_with_intentions(commits, intention_map)
+    }
 
-#[cfg(test)]
-mod changes_tests {
-    use crate::changes::{Changes, RepositoryExtension};
-    use crate::repo::{ConventionalCommit, VersionTag};
-    use crate::test_util::{repo_init, MockError, RepositoryTestExtensions};
-    use git2::Oid;
-    use semver::Version;
-    use std::error::Error;
+    /// Sort already fetched commits into change categories according to their commit
+    /// intentions, without accessing a repository.
+    ///
+    /// Commits without a known intention are ignored.
+    ///
+    /// ## Returns
+    ///
+    /// The [`Changes`] structure with the sorted commits.
+    ///
+    /// ## Example
+    /// ```
+    /// use cargo_semantic_release::{Changes, ConventionalCommit, SemanticVersionAction};
+    ///
+    /// let commits = vec![ConventionalCommit {
+    ///     message: ":bug: fix the parser".to_string(),
+    ///     hash: "3c8e19bf7fe7ba3dd6f0489374a5200fad124377".to_string(),
+    ///     ..Default::default()
+    /// }];
+    ///
+    /// let action = Changes::from_commits(commits).define_action_for_semantic_version();
+    /// assert_eq!(action, SemanticVersionAction::IncrementPatch);
+    /// ```
+    pub fn from_commits(commits: Vec<ConventionalCommit>) -> Self {
+        Self::from_commits_with_intentions(commits, &IntentionMap::default())
+    }
 
-    fn convert(messages: Vec<&str>) -> Vec<ConventionalCommit> {
-        messages
-            .iter()
-            .map(|commit_message| ConventionalCommit {
-                message: commit_message.to_string(),
-                hash: "".to_string(),
-            })
-            .collect()
+    /// Sort [`git2::Commit`] objects into change categories like [`Changes::from_commits`].
+    ///
+    /// ## Returns
+    ///
+    /// The [`Changes`] structure with the sorted commits.
+    pub fn from_git2_commits(commits: Vec<git2::Commit>) -> Self {
+        Self::from_commits(
+            commits
+                .into_iter()
+                .map(ConventionalCommit::from_git2_commit)
+                .collect(),
+        )
     }
 
-    struct MockedRepository {
+    /// Sort the commits into change categories according to the given [`IntentionMap`].
+    pub fn from_commits_with_intentions(
         commits: Vec<ConventionalCommit>,
-        commit_fetching_fails: bool,
-        commit_with_latest_tag: Option<String>,
-        latest_version_tag: Option<VersionTag>,
-        tag_fetching_fails: bool,
+        intention_map: &IntentionMap,
+    ) -> Self {
+        let mut changes = Self::default();
+        for commit in commits {
+            changes.append_commit_with_intentions(commit, intention_map);
+        }
+        changes
     }
 
-    impl RepositoryExtension for MockedRepository {
-        fn fetch_commits_until(
-            &self,
-            stop_oid: Oid,
-        ) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
-            assert_eq!(
-                stop_oid,
-                self.latest_version_tag.as_ref().unwrap().commit_oid,
-                "fetch_commits_until is not called with the latest version tag"
-            );
-            if self.commit_fetching_fails {
-                Err(Box::new(MockError))
-            } else {
-                let commits = self
-                    .commits
-                    .clone()
-                    .into_iter()
-                    .rev()
-                    .map(|commit| commit.message.clone())
-                    .take_while(|message| {
-                        message.as_str() != self.commit_with_latest_tag.as_ref().unwrap().as_str()
-                    })
-                    .map(|message| ConventionalCommit {
-                        message,
-                        hash: "".to_string(),
-                    })
-                    .collect();
-                Ok(commits)
-            }
-        }
+    /// Classify a commit and add it to the commits of its change category, e.g. for
+    /// building the changes from commits that arrive one at a time.
+    ///
+    /// Commits without a known intention are dropped like in [`Changes::from_repo`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::{Category, Changes, ConventionalCommit};
+    ///
+    /// let mut changes = Changes::default();
+    /// changes.append_commit(ConventionalCommit {
+    ///     message: ":sparkles: add feature".to_string(),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// assert_eq!(changes.commits(Category::Minor).len(), 1);
+    /// ```
+    pub fn append_commit(&mut self, commit: ConventionalCommit) {
+        self.append_commit_with_intentions(commit, &IntentionMap::default());
+    }
 
-        fn fetch_all_commits(&self) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
-            if self.commit_fetching_fails {
-                Err(Box::new(MockError))
-            } else {
-                Ok(self.commits.clone())
-            }
+    /// Classify a commit according to the given [`IntentionMap`] and add it to the commits
+    /// of its change category.
+    ///
+    /// A commit with intentions of several categories is added to a single category only,
+    /// the one chosen by [`IntentionMap::category_of`].
+    pub fn append_commit_with_intentions(
+        &mut self,
+        commit: ConventionalCommit,
+        intention_map: &IntentionMap,
+    ) {
+        self.analyzed += 1;
+        match commit.category(intention_map) {
+            Some(Category::Major) => self.major.push(commit),
+            Some(Category::Minor) => self.minor.push(commit),
+            Some(Category::Patch) => self.patch.push(commit),
+            Some(Category::Other) => self.other.push(commit),
+            None => self.unrecognized.push(commit),
         }
+    }
 
-        fn get_latest_version_tag(&self) -> Result<Option<VersionTag>, Box<dyn Error>> {
-            if self.tag_fetching_fails {
-                Err(Box::new(MockError))
+    /// Evaluate the changes find in a repository to figure out the semantic version action
+    ///
+    /// ## Returns
+    ///
+    /// [`SemanticVersionAction`] enum for the suggested semantic version change.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///  use git2::Repository;
+    ///  use cargo_semantic_release::Changes;
+    ///
+    ///  let git_repo = Repository::open(".").unwrap();
+    ///
+    ///  let action = Changes::from_repo(&git_repo).expect("Error during fetching changes").define_action_for_semantic_version();
+    ///  println!("suggested change of semantic version: {}", action);
+    /// ```
+    pub fn define_action_for_semantic_version(&self) -> SemanticVersionAction {
+        self.define_action_with_options(&ActionOptions::default())
+    }
+
+    /// Evaluate the changes like [`Changes::define_action_for_semantic_version`], but
+    /// according to the given [`ActionOptions`].
+    ///
+    /// ## Returns
+    ///
+    /// [`SemanticVersionAction`] enum for the suggested semantic version change.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///  use git2::Repository;
+    ///  use cargo_semantic_release::{ActionOptions, Changes};
+    ///
+    ///  let git_repo = Repository::open(".").unwrap();
+    ///  let options = ActionOptions {
+    ///      docs_trigger_patch: true,
+    ///      ..Default::default()
+    ///  };
+    ///
+    ///  let action = Changes::from_repo(&git_repo)
+    ///      .expect("Error during fetching changes")
+    ///      .define_action_with_options(&options);
+    ///  println!("suggested change of semantic version: {}", action);
+    /// ```
+    pub fn define_action_with_options(&self, options: &ActionOptions) -> SemanticVersionAction {
+        let action = self.define_action_from_commits(options);
+        let is_initial_development = options
+            .current_version
+            .as_ref()
+            .is_some_and(|current_version| current_version.major == 0);
+        let action = match action {
+            _ if options.zero_ver == ZeroVerMode::Loose || !is_initial_development => action,
+            SemanticVersionAction::IncrementMajor => SemanticVersionAction::IncrementMinor,
+            SemanticVersionAction::IncrementMinor => SemanticVersionAction::IncrementPatch,
+            action => action,
+        };
+        options
+            .min_bump
+            .map_or(action, |min_bump| action.max(min_bump))
+    }
+
+    /// Define the action from the commits of the changes, without the minimum bump level.
+    fn define_action_from_commits(&self, options: &ActionOptions) -> SemanticVersionAction {
+        if !self.major.is_empty() {
+            return SemanticVersionAction::IncrementMajor;
+        }
+        if !self.minor.is_empty() {
+            return SemanticVersionAction::IncrementMinor;
+        }
+        if !self.patch.is_empty() {
+            return SemanticVersionAction::IncrementPatch;
+        }
+        if options.docs_trigger_patch
+            && !self.other.is_empty()
+            && get_commits_with_intention(self.other.clone(), documentation_gitmojis()).len()
+                == self.other.len()
+        {
+            return SemanticVersionAction::IncrementPatch;
+        }
+        if options.release_overdue && !self.other.is_empty() {
+            return SemanticVersionAction::IncrementPatch;
+        }
+        SemanticVersionAction::Keep
+    }
+
+    /// Find the commit that drives the semantic version action.
+    ///
+    /// ## Returns
+    ///
+    /// The first commit of the highest change category that has commits, together with its
+    /// [`Category`], or `None` if there are no changes.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///  use git2::Repository;
+    ///  use cargo_semantic_release::Changes;
+    ///
+    ///  let git_repo = Repository::open(".").unwrap();
+    ///
+    ///  let changes = Changes::from_repo(&git_repo).expect("Error during fetching changes");
+    ///  if let Some((commit, category)) = changes.trigger() {
+    ///      println!("{category} change triggered by {commit}");
+    ///  }
+    /// ```
+    pub fn trigger(&self) -> Option<(&ConventionalCommit, Category)> {
+        [
+            (&self.major, Category::Major),
+            (&self.minor, Category::Minor),
+            (&self.patch, Category::Patch),
+            (&self.other, Category::Other),
+        ]
+        .into_iter()
+        .find_map(|(commits, category)| commits.first().map(|commit| (commit, category)))
+    }
+
+    /// Return the commits of a change category.
+    pub fn commits(&self, category: Category) -> &[ConventionalCommit] {
+        match category {
+            Category::Major => &self.major,
+            Category::Minor => &self.minor,
+            Category::Patch => &self.patch,
+            Category::Other => &self.other,
+        }
+    }
+
+    /// Remove every commit of a change category, e.g. for leaving the other changes out of
+    /// a changelog. The number of analyzed commits is kept.
+    pub fn remove_category(&mut self, category: Category) {
+        match category {
+            Category::Major => self.major.clear(),
+            Category::Minor => self.minor.clear(),
+            Category::Patch => self.patch.clear(),
+            Category::Other => self.other.clear(),
+        }
+    }
+
+    /// Count the commits in each change category.
+    ///
+    /// ## Returns
+    ///
+    /// [`ChangeCounts`] with the number of commits per category.
+    pub fn counts(&self) -> ChangeCounts {
+        ChangeCounts {
+            major: self.major.len(),
+            minor: self.minor.len(),
+            patch: self.patch.len(),
+            other: self.other.len(),
+        }
+    }
+
+    /// Return the number of commits that were analyzed, including the commits without a
+    /// known intention and the commits dropped after the analysis, e.g. by
+    /// [`Changes::exclude_authors`].
+    pub fn total_commits_analyzed(&self) -> usize {
+        self.analyzed
+    }
+
+    /// Return the analyzed commits without a known intention, which don't belong to any
+    /// change category, e.g. for enforcing that every commit has an intention.
+    pub fn unrecognized(&self) -> &[ConventionalCommit] {
+        &self.unrecognized
+    }
+
+    /// Count the commits in each change category grouped by the scope of the commits.
+    ///
+    /// Commits without a scope are counted under the `""` key.
+    ///
+    /// ## Returns
+    ///
+    /// Map of the scopes and the [`ChangeCounts`] of the commits with that scope.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///  use git2::Repository;
+    ///  use cargo_semantic_release::Changes;
+    ///
+    ///  let git_repo = Repository::open(".").unwrap();
+    ///
+    ///  let changes = Changes::from_repo(&git_repo).expect("Error during fetching changes");
+    ///  for (scope, counts) in changes.counts_by_scope() {
+    ///      println!("{scope}: {counts}");
+    ///  }
+    /// ```
+    pub fn counts_by_scope(&self) -> BTreeMap<String, ChangeCounts> {
+        let mut counts: BTreeMap<String, ChangeCounts> = BTreeMap::new();
+        for commit in &self.major {
+            counts.entry(commit.scope().to_string()).or_default().major += 1;
+        }
+        for commit in &self.minor {
+            counts.entry(commit.scope().to_string()).or_default().minor += 1;
+        }
+        for commit in &self.patch {
+            counts.entry(commit.scope().to_string()).or_default().patch += 1;
+        }
+        for commit in &self.other {
+            counts.entry(commit.scope().to_string()).or_default().other += 1;
+        }
+        counts
+    }
+
+    /// Append the commits of other changes to the commits of these changes.
+    ///
+    /// The commits are concatenated per change category, e.g. for combining the changes
+    /// of multiple branches.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///  use git2::Repository;
+    ///  use cargo_semantic_release::Changes;
+    ///
+    ///  let git_repo = Repository::open(".").unwrap();
+    ///
+    ///  let mut changes = Changes::default();
+    ///  changes.merge(Changes::from_repo(&git_repo).expect("Error during fetching changes"));
+    ///  println!("merged changes: {changes}");
+    /// ```
+    pub fn merge(&mut self, other: Changes) {
+        self.major.extend(other.major);
+        self.minor.extend(other.minor);
+        self.patch.extend(other.patch);
+        self.other.extend(other.other);
+        self.analyzed += other.analyzed;
+        self.unrecognized.extend(other.unrecognized);
+    }
+
+    /// Collect the commits of these changes that are missing from other changes.
+    ///
+    /// The commits are compared per change category, e.g. for finding the changes since
+    /// a previous analysis.
+    ///
+    /// ## Returns
+    ///
+    /// [`Changes`] with the commits that are present in `self`, but not in `other`. Only
+    /// these commits count as analyzed.
+    pub fn difference(&self, other: &Changes) -> Changes {
+        let mut difference = Changes {
+            major: difference_of(&self.major, &other.major),
+            minor: difference_of(&self.minor, &other.minor),
+            patch: difference_of(&self.patch, &other.patch),
+            other: difference_of(&self.other, &other.other),
+            analyzed: 0,
+            unrecognized: Vec::new(),
+        };
+        difference.analyzed = (&difference).into_iter().count();
+        difference
+    }
+
+    /// Prefix the hashes of the commits with a namespace, e.g. the path of a submodule.
+    ///
+    /// The hashes become `<namespace>:<hash>`. Hashes that already have a namespace are
+    /// nested into the new one.
+    pub fn namespace_hashes(&mut self, namespace: &str) {
+        for commit in self
+            .major
+            .iter_mut()
+            .chain(&mut self.minor)
+            .chain(&mut self.patch)
+            .chain(&mut self.other)
+        {
+            commit.hash = if commit.namespace().is_empty() {
+                format!("{namespace}:{}", commit.hash)
             } else {
-                Ok(self.latest_version_tag.clone())
+                format!("{namespace}/{}", commit.hash)
+            };
+        }
+    }
+
+    /// Collect the authors of the commits in all change categories.
+    ///
+    /// ## Returns
+    ///
+    /// Unique `(name, email)` pairs of the authors, sorted by name.
+    pub fn contributors(&self) -> Vec<(String, String)> {
+        self.major
+            .iter()
+            .chain(&self.minor)
+            .chain(&self.patch)
+            .chain(&self.other)
+            .map(|commit| (commit.author_name.clone(), commit.author_email.clone()))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Create the structured [`Report`] of the changes.
+    ///
+    /// The action is defined with [`Changes::define_action_for_semantic_version`].
+    ///
+    /// ## Returns
+    ///
+    /// The [`Report`] with the next version computed from the `current` version.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///  use git2::Repository;
+    ///  use cargo_semantic_release::Changes;
+    ///
+    ///  let git_repo = Repository::open(".").unwrap();
+    ///
+    ///  let changes = Changes::from_repo(&git_repo).expect("Error during fetching changes");
+    ///  for (category, subject, hash, _) in changes.report(None).entries {
+    ///      println!("{category}: {subject} ({hash})");
+    ///  }
+    /// ```
+    pub fn report(&self, current: Option<Version>) -> Report {
+        let action = self.define_action_for_semantic_version();
+        let entries = self
+            .into_iter()
+            .map(|(category, commit)| {
+                (
+                    category,
+                    commit.subject().to_string(),
+                    commit.short_hash().to_string(),
+                    commit.author_date(),
+                )
+            })
+            .collect();
+        Report {
+            counts: self.counts(),
+            total_commits_analyzed: self.total_commits_analyzed(),
+            action,
+            next_version: current.map(|version| action.next_version(&version)),
+            entries,
+        }
+    }
+
+    /// Format the values in [`Changes`] like its [`Display`] implementation does, but
+    /// abbreviate the commit hashes to `hash_len` characters.
+    pub fn format_with_hash_len(&self, hash_len: usize) -> String {
+        self.counts()
+            .table_rows()
+            .into_iter()
+            .map(|(category, row)| {
+                let commits: String = self
+                    .commits(category)
+                    .iter()
+                    .map(|commit| format!("\n    {}", commit.format_commit(hash_len)))
+                    .collect();
+                format!("{row}{commits}")
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Remove the commits that were reverted within the analyzed changes, together with
+    /// the `:rewind:` commits that reverted them.
+    ///
+    /// A revert commit is matched with the reverted commit by the `This reverts commit <hash>`
+    /// line of its message. Revert commits whose reverted commit is not part of the changes
+    /// are kept.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///  use git2::Repository;
+    ///  use cargo_semantic_release::Changes;
+    ///
+    ///  let git_repo = Repository::open(".").unwrap();
+    ///
+    ///  let mut changes = Changes::from_repo(&git_repo).expect("Error during fetching changes");
+    ///  changes.collapse_reverts();
+    ///  println!("changes without reverted commits: {changes}");
+    /// ```
+    pub fn collapse_reverts(&mut self) {
+        let revert_commits: Vec<ConventionalCommit> =
+            [&self.major, &self.minor, &self.patch, &self.other]
+                .into_iter()
+                .flatten()
+                .filter(|commit| {
+                    commit.message.contains(":rewind:") || commit.message.contains("⏪️")
+                })
+                .filter(|commit| commit.reverted_hash().is_some())
+                .cloned()
+                .collect();
+
+        for revert_commit in revert_commits {
+            let reverted_hash = revert_commit.reverted_hash().unwrap();
+            let mut is_reverted_commit_found = false;
+            for bucket in [
+                &mut self.major,
+                &mut self.minor,
+                &mut self.patch,
+                &mut self.other,
+            ] {
+                let original_length = bucket.len();
+                bucket.retain(|commit| {
+                    commit.namespace() != revert_commit.namespace()
+                        || !commit.object_hash().starts_with(reverted_hash)
+                });
+                is_reverted_commit_found |= bucket.len() != original_length;
+            }
+            if is_reverted_commit_found {
+                for bucket in [
+                    &mut self.major,
+                    &mut self.minor,
+                    &mut self.patch,
+                    &mut self.other,
+                ] {
+                    bucket.retain(|commit| *commit != revert_commit);
+                }
+            }
+        }
+    }
+
+    /// Remove the commits of the authors matching any of the patterns, e.g. bots like
+    /// `dependabot[bot]`.
+    ///
+    /// A pattern matches if the name or the email address of the author contains it. The
+    /// patterns are plain text, so brackets and dots match themselves. The removed commits
+    /// don't count toward the semantic version action, and they are not reported as
+    /// unrecognized either.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///  use git2::Repository;
+    ///  use cargo_semantic_release::Changes;
+    ///
+    ///  let git_repo = Repository::open(".").unwrap();
+    ///
+    ///  let mut changes = Changes::from_repo(&git_repo).expect("Error during fetching changes");
+    ///  changes.exclude_authors(&["dependabot[bot]".to_string(), "renovate".to_string()]);
+    ///  println!("changes without bot commits: {changes}");
+    /// ```
+    pub fn exclude_authors(&mut self, patterns: &[String]) {
+        let is_excluded = |commit: &ConventionalCommit| {
+            [&commit.author_name, &commit.author_email]
+                .into_iter()
+                .any(|author| {
+                    patterns
+                        .iter()
+                        .any(|pattern| author.contains(pattern.as_str()))
+                })
+        };
+        for bucket in [
+            &mut self.major,
+            &mut self.minor,
+            &mut self.patch,
+            &mut self.other,
+            &mut self.unrecognized,
+        ] {
+            bucket.retain(|commit| !is_excluded(commit));
+        }
+    }
+
+    /// Remove the commits whose message contains the marker, e.g. `[skip release]`, whatever
+    /// their intention is. The removed commits don't count toward the semantic version action.
+    /// An empty marker removes nothing.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///  use cargo_semantic_release::Changes;
+    ///
+    ///  let mut changes = Changes::from_messages(vec![
+    ///      ":sparkles: add feature [skip release]".to_string(),
+    ///      ":bug: fix bug".to_string(),
+    ///  ]);
+    ///  changes.exclude_marked("[skip release]");
+    ///  println!("changes to release: {changes}");
+    /// ```
+    pub fn exclude_marked(&mut self, marker: &str) {
+        if marker.is_empty() {
+            return;
+        }
+        for bucket in [
+            &mut self.major,
+            &mut self.minor,
+            &mut self.patch,
+            &mut self.other,
+            &mut self.unrecognized,
+        ] {
+            bucket.retain(|commit| !commit.message.contains(marker));
+        }
+    }
+}
+
+impl TryFrom<&Repository> for Changes {
+    type Error = ChangesError;
+
+    /// Create [`Changes`] from a repository with [`Changes::from_repo`].
+    ///
+    /// # Returns
+    ///
+    /// The [`Changes`] of the repository, [`ChangesError::NoCommits`] if `HEAD` doesn't point
+    /// to any commit yet (e.g. the repository is empty) and [`ChangesError::Other`] for any
+    /// other error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use git2::Repository;
+    /// use cargo_semantic_release::{Changes, ChangesError, SemanticVersionAction};
+    ///
+    /// let git_repo = Repository::open(".").unwrap();
+    ///
+    /// let action = match Changes::try_from(&git_repo) {
+    ///     Ok(changes) => changes.define_action_for_semantic_version(),
+    ///     Err(ChangesError::NoCommits) => SemanticVersionAction::Keep,
+    ///     Err(error) => panic!("error during fetching changes: {error}"),
+    /// };
+    /// ```
+    fn try_from(value: &Repository) -> Result<Self, Self::Error> {
+        if let Err(error) = value.head() {
+            if matches!(error.code(), ErrorCode::UnbornBranch | ErrorCode::NotFound) {
+                return Err(ChangesError::NoCommits);
             }
         }
+        Self::from_repo(value).map_err(ChangesError::Other)
+    }
+}
+
+/// Error type for creating [`Changes`] from a repository
+#[derive(Debug)]
+pub enum ChangesError {
+    /// `HEAD` of the repository doesn't point to any commit
+    NoCommits,
+    /// Error during fetching the changes from the repository
+    Other(Box<dyn Error>),
+}
+
+impl Display for ChangesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangesError::NoCommits => write!(f, "repository doesn't have any commits"),
+            ChangesError::Other(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for ChangesError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ChangesError::NoCommits => None,
+            ChangesError::Other(error) => Some(error.as_ref()),
+        }
+    }
+}
+
+impl PartialEq for Changes {
+    /// Compare two [`Changes`] struct to see if they have the same elements.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the two structure has the same elements regardless they order, `false` otherwise.
+    /// A commit that appears multiple times in a change category has to appear the same
+    /// number of times in the other structure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use git2::AttrValue::True;
+    /// use git2::Repository;
+    /// use cargo_semantic_release::Changes;
+    ///
+    /// let git_repo = Repository::open(".").unwrap();
+    ///
+    /// let changes_1 = Changes::from_repo(&git_repo).expect("error during fetching changes");
+    /// let changes_2 = Changes::from_repo(&git_repo).expect("error during fetching changes");
+    ///
+    /// assert_eq!(changes_1, changes_2);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        have_same_commits(&self.major, &other.major)
+            && have_same_commits(&self.minor, &other.minor)
+            && have_same_commits(&self.patch, &other.patch)
+            && have_same_commits(&self.other, &other.other)
+    }
+}
+
+impl Eq for Changes {}
+
+impl IntoIterator for Changes {
+    type Item = (Category, ConventionalCommit);
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    /// Iterate over the commits of all change categories, from the major to the other
+    /// changes, keeping the order of the commits within each category.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    ///  use git2::Repository;
+    ///  use cargo_semantic_release::Changes;
+    ///
+    ///  let git_repo = Repository::open(".").unwrap();
+    ///
+    ///  let changes = Changes::from_repo(&git_repo).expect("Error during fetching changes");
+    ///  for (category, commit) in &changes {
+    ///      println!("{category}: {commit}");
+    ///  }
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        [
+            (Category::Major, self.major),
+            (Category::Minor, self.minor),
+            (Category::Patch, self.patch),
+            (Category::Other, self.other),
+        ]
+        .into_iter()
+        .flat_map(|(category, commits)| commits.into_iter().map(move |commit| (category, commit)))
+        .collect::<Vec<_>>()
+        .into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Changes {
+    type Item = (Category, &'a ConventionalCommit);
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    /// Iterate over the commits of all change categories like [`Changes::into_iter`], but
+    /// by reference.
+    fn into_iter(self) -> Self::IntoIter {
+        [
+            Category::Major,
+            Category::Minor,
+            Category::Patch,
+            Category::Other,
+        ]
+        .into_iter()
+        .flat_map(|category| {
+            self.commits(category)
+                .iter()
+                .map(move |commit| (category, commit))
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+    }
+}
+
+impl Display for Changes {
+    /// Format the values in [`Changes`]
+    ///
+    /// Example output:
+    /// ```shell
+    /// major  1
+    ///     :boom: Introduce breaking change - 3c8e19b
+    /// minor  1
+    ///     :sparkles: Add new feature - 9d5f3a1
+    /// patch 12
+    ///     :recycle: Refactor codebase - 0a1b2c3
+    ///     ...
+    /// other  0
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format_with_hash_len(SHORT_HASH_LEN))
+    }
+}
+
+/// Options that control how the semantic version action is defined from the changes
+#[derive(Debug, Clone, Default)]
+pub struct ActionOptions {
+    /// Increment the patch version when the only changes are documentation changes
+    pub docs_trigger_patch: bool,
+    /// Increment the patch version when the only changes are other changes, because
+    /// the latest release is overdue
+    pub release_overdue: bool,
+    /// Minimum action to take, even if the changes define a lower one. The action is not
+    /// raised when `None`.
+    pub min_bump: Option<SemanticVersionAction>,
+    /// Version before the changes, e.g. from the latest version tag. Only used with
+    /// [`ZeroVerMode::Strict`].
+    pub current_version: Option<Version>,
+    /// How the changes are handled while the major version of the current version is 0
+    pub zero_ver: ZeroVerMode,
+}
+
+/// Enum to represent how the changes are handled during the `0.x` initial development
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ZeroVerMode {
+    /// Breaking changes increment the major version even during initial development
+    #[default]
+    Loose,
+    /// Breaking changes increment the minor version and features increment the patch
+    /// version while the major version is 0
+    Strict,
+}
+
+/// Number of commits in each change category
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, schemars::JsonSchema))]
+pub struct ChangeCounts {
+    /// Number of commits with major changes
+    pub major: usize,
+    /// Number of commits with minor changes
+    pub minor: usize,
+    /// Number of commits with patch changes
+    pub patch: usize,
+    /// Number of commits with other changes
+    pub other: usize,
+}
+
+impl ChangeCounts {
+    /// Format the counts as the rows of a table, with the categories left-aligned and the
+    /// counts right-aligned in their own columns.
+    ///
+    /// The rows don't contain colors, so they can be styled as a whole without breaking the
+    /// alignment.
+    /// ## Returns
+    /// The category of each row with the formatted row, from major to other.
+    pub fn table_rows(&self) -> Vec<(Category, String)> {
+        let rows = [
+            (Category::Major, self.major),
+            (Category::Minor, self.minor),
+            (Category::Patch, self.patch),
+            (Category::Other, self.other),
+        ];
+        let category_width = rows
+            .iter()
+            .map(|(category, _)| category.to_string().len())
+            .max()
+            .unwrap_or_default();
+        let count_width = rows
+            .iter()
+            .map(|(_, count)| count.to_string().len())
+            .max()
+            .unwrap_or_default();
+        rows.into_iter()
+            .map(|(category, count)| {
+                let name = category.to_string();
+                (
+                    category,
+                    format!("{name:<category_width$} {count:>count_width$}"),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Display for ChangeCounts {
+    /// Format the values in [`ChangeCounts`]
+    ///
+    /// Example output:
+    /// ```shell
+    /// major=1 minor=3 patch=0 other=5
+    /// ```
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "major={} minor={} patch={} other={}",
+            self.major, self.minor, self.patch, self.other
+        )
+    }
+}
+
+/// Enum to represent the action for semantic version
+///
+/// Actions are ordered by the size of the version change:
+/// `Keep < IncrementPatch < IncrementMinor < IncrementMajor`.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum SemanticVersionAction {
+    Keep,
+    IncrementPatch,
+    IncrementMinor,
+    IncrementMajor,
+}
+
+impl SemanticVersionAction {
+    /// Apply the action to the current version.
+    ///
+    /// Incrementing a version component resets the lower components, the pre-release and
+    /// the build metadata.
+    ///
+    /// ## Returns
+    ///
+    /// The next version after the action is applied.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::SemanticVersionAction;
+    /// use semver::Version;
+    ///
+    /// let next_version = SemanticVersionAction::IncrementMinor.next_version(&Version::new(1, 2, 3));
+    /// assert_eq!(next_version, Version::new(1, 3, 0));
+    /// ```
+    pub fn next_version(&self, current: &Version) -> Version {
+        let mut next = match self {
+            SemanticVersionAction::IncrementMajor => Version::new(current.major + 1, 0, 0),
+            SemanticVersionAction::IncrementMinor => {
+                Version::new(current.major, current.minor + 1, 0)
+            }
+            SemanticVersionAction::IncrementPatch => {
+                Version::new(current.major, current.minor, current.patch + 1)
+            }
+            SemanticVersionAction::Keep => return current.clone(),
+        };
+        next.pre = Prerelease::EMPTY;
+        next.build = BuildMetadata::EMPTY;
+        next
+    }
+    /// Apply the action to the current version, producing a pre-release version with the
+    /// given identifier and a counter, e.g. `1.3.0-rc.1`.
+    ///
+    /// If the current version is already a pre-release with the same identifier, only the
+    /// counter is incremented and the rest of the version is kept, e.g. `1.3.0-rc.1` becomes
+    /// `1.3.0-rc.2`. The version is kept when the action is [`SemanticVersionAction::Keep`].
+    ///
+    /// ## Returns
+    ///
+    /// The next pre-release version, or an error if the identifier is invalid.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::SemanticVersionAction;
+    /// use semver::Version;
+    ///
+    /// let action = SemanticVersionAction::IncrementMinor;
+    /// let next_version = action.next_pre_release(&Version::new(1, 2, 3), "rc").unwrap();
+    /// assert_eq!(next_version, Version::parse("1.3.0-rc.1").unwrap());
+    /// ```
+    pub fn next_pre_release(
+        &self,
+        current: &Version,
+        identifier: &str,
+    ) -> Result<Version, Box<dyn Error>> {
+        if *self == SemanticVersionAction::Keep {
+            return Ok(current.clone());
+        }
+        let current_counter = current
+            .pre
+            .as_str()
+            .strip_prefix(identifier)
+            .and_then(|counter| counter.strip_prefix('.'))
+            .and_then(|counter| counter.parse::<u64>().ok());
+
+        let (mut next, counter) = match current_counter {
+            Some(counter) => (current.clone(), counter + 1),
+            None => (self.next_version(current), 1),
+        };
+        next.pre = Prerelease::new(&format!("{identifier}.{counter}"))
+            .map_err(|error| format!("invalid pre-release identifier '{identifier}': {error}"))?;
+        next.build = BuildMetadata::EMPTY;
+        Ok(next)
+    }
+}
+
+/// Analyze a repository like [`Changes::from_repo`] and define the action for its semantic
+/// version in one call.
+///
+/// ## Returns
+///
+/// The [`Changes`] of the repository together with the suggested [`SemanticVersionAction`],
+/// or error type.
+///
+/// ## Example
+///
+/// ```
+/// use git2::Repository;
+/// use cargo_semantic_release::analyze;
+///
+/// let git_repo = Repository::open(".").unwrap();
+///
+/// let (changes, action) = analyze(&git_repo).expect("error during analyzing the repository");
+/// println!("changes: {changes}\naction: {action}");
+/// ```
+pub fn analyze(
+    repository: &impl RepositoryExtension,
+) -> Result<(Changes, SemanticVersionAction), Box<dyn Error>> {
+    let changes = Changes::from_repo(repository)?;
+    let action = changes.define_action_for_semantic_version();
+    Ok((changes, action))
+}
+
+/// Check that the next version is greater than the current version, unless the action is
+/// [`SemanticVersionAction::Keep`] in which case the versions must be the same.
+///
+/// ## Returns
+///
+/// `Ok` if the next version is valid, error message otherwise.
+pub fn validate_monotonic(
+    current: &Version,
+    next: &Version,
+    action: &SemanticVersionAction,
+) -> Result<(), String> {
+    match action {
+        SemanticVersionAction::Keep if next != current => Err(format!(
+            "next version {next} differs from current version {current} although the version is kept"
+        )),
+        SemanticVersionAction::Keep => Ok(()),
+        _ if next <= current => Err(format!(
+            "next version {next} is not greater than current version {current} ({action})"
+        )),
+        _ => Ok(()),
+    }
+}
+
+impl Display for SemanticVersionAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            SemanticVersionAction::IncrementMajor => "increment major version",
+            SemanticVersionAction::IncrementMinor => "increment minor version",
+            SemanticVersionAction::IncrementPatch => "increment patch version",
+            SemanticVersionAction::Keep => "keep version",
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+impl FromStr for SemanticVersionAction {
+    type Err = String;
+
+    /// Parse an action from the name of its version change, e.g. `minor`, ignoring the case.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name.to_ascii_lowercase().as_str() {
+            "major" => Ok(SemanticVersionAction::IncrementMajor),
+            "minor" => Ok(SemanticVersionAction::IncrementMinor),
+            "patch" => Ok(SemanticVersionAction::IncrementPatch),
+            "keep" => Ok(SemanticVersionAction::Keep),
+            _ => Err(format!(
+                "unknown action `{name}`, expected major, minor, patch or keep"
+            )),
+        }
+    }
+}
+
+/// Check whether two lists have the same commits, regardless of their order but counting
+/// the duplicated commits.
+fn have_same_commits(commits: &[ConventionalCommit], other_commits: &[ConventionalCommit]) -> bool {
+    let mut occurrences: HashMap<&ConventionalCommit, isize> = HashMap::new();
+    for commit in commits {
+        *occurrences.entry(commit).or_default() += 1;
+    }
+    for commit in other_commits {
+        *occurrences.entry(commit).or_default() -= 1;
+    }
+    occurrences.values().all(|occurrence| *occurrence == 0)
+}
+
+fn difference_of(
+    commits: &[ConventionalCommit],
+    other_commits: &[ConventionalCommit],
+) -> Vec<ConventionalCommit> {
+    let other_commits: HashSet<_> = other_commits.iter().collect();
+    commits
+        .iter()
+        .filter(|commit| !other_commits.contains(commit))
+        .cloned()
+        .collect()
+}
+
+/// Fetch the commits since the latest version tag, or all the commits if there are no version
+/// tags yet.
+fn fetch_commits_since_latest_tag(
+    repository: &impl RepositoryExtension,
+    options: &FetchOptions,
+) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
+    let version_tag = if options.reachable_tags_only {
+        match &options.reference {
+            Some(reference) => {
+                repository.latest_version_tag_reachable_from(reference, &options.tag_prefixes)?
+            }
+            None => repository.latest_reachable_version_tag_with_prefixes(&options.tag_prefixes)?,
+        }
+    } else {
+        repository.latest_version_tag_with_scheme(&options.tag_prefixes, options.scheme)?
+    };
+
+    match version_tag {
+        Some(version_tag) => repository.fetch_commits_until(version_tag.commit_oid, options),
+        None => repository.fetch_all_commits(options),
+    }
+}
+
+fn get_commits_with_intention(
+    commits: Vec<ConventionalCommit>,
+    intentions: impl Iterator<Item = &'static Gitmoji>,
+) -> Vec<ConventionalCommit> {
+    let intentions: Vec<&Gitmoji> = intentions.collect();
+    commits
+        .into_iter()
+        .filter(|commit| {
+            intentions.iter().any(|intention| {
+                commit.message.contains(intention.0) || commit.message.contains(intention.1)
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod changes_tests {
+    use crate::changes::{analyze, Changes, ChangesError, RepositoryExtension};
+    use crate::intention::{Category, IntentionMap};
+    use crate::repo::{
+        ConventionalCommit, DuplicateVersionTag, FetchOptions, TagKind, VersionScheme, VersionTag,
+    };
+    use crate::test_util::{repo_init, MockError, RepositoryTestExtensions};
+    use git2::Oid;
+    use semver::Version;
+    use std::error::Error;
+
+    fn convert(messages: Vec<&str>) -> Vec<ConventionalCommit> {
+        messages
+            .iter()
+            .map(|commit_message| ConventionalCommit {
+                message: commit_message.to_string(),
+                hash: "".to_string(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    struct MockedRepository {
+        commits: Vec<ConventionalCommit>,
+        commit_fetching_fails: bool,
+        commit_with_latest_tag: Option<String>,
+        latest_version_tag: Option<VersionTag>,
+        tag_fetching_fails: bool,
+    }
+
+    impl RepositoryExtension for MockedRepository {
+        fn fetch_commits_until(
+            &self,
+            stop_oid: Oid,
+            _options: &FetchOptions,
+        ) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
+            assert_eq!(
+                stop_oid,
+                self.latest_version_tag.as_ref().unwrap().commit_oid,
+                "fetch_commits_until is not called with the latest version tag"
+            );
+            if self.commit_fetching_fails {
+                Err(Box::new(MockError))
+            } else {
+                let commits = self
+                    .commits
+                    .clone()
+                    .into_iter()
+                    .rev()
+                    .map(|commit| commit.message.clone())
+                    .take_while(|message| {
+                        message.as_str() != self.commit_with_latest_tag.as_ref().unwrap().as_str()
+                    })
+                    .map(|message| ConventionalCommit {
+                        message,
+                        hash: "".to_string(),
+                        ..Default::default()
+                    })
+                    .collect();
+                Ok(commits)
+            }
+        }
+
+        fn fetch_all_commits(
+            &self,
+            _options: &FetchOptions,
+        ) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
+            if self.commit_fetching_fails {
+                Err(Box::new(MockError))
+            } else {
+                Ok(self.commits.clone())
+            }
+        }
+
+        fn fetch_commits_in_range(
+            &self,
+            _range: &str,
+            options: &FetchOptions,
+        ) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
+            self.fetch_all_commits(options)
+        }
+
+        fn latest_version_tag_with_scheme(
+            &self,
+            prefixes: &[String],
+            _scheme: VersionScheme,
+        ) -> Result<Option<VersionTag>, Box<dyn Error>> {
+            self.get_latest_version_tag_with_prefixes(prefixes)
+        }
+
+        fn get_latest_version_tag_with_prefixes(
+            &self,
+            _prefixes: &[String],
+        ) -> Result<Option<VersionTag>, Box<dyn Error>> {
+            if self.tag_fetching_fails {
+                Err(Box::new(MockError))
+            } else {
+                Ok(self.latest_version_tag.clone())
+            }
+        }
+
+        fn latest_reachable_version_tag_with_prefixes(
+            &self,
+            prefixes: &[String],
+        ) -> Result<Option<VersionTag>, Box<dyn Error>> {
+            self.get_latest_version_tag_with_prefixes(prefixes)
+        }
+
+        fn latest_version_tag_reachable_from(
+            &self,
+            _reference: &str,
+            prefixes: &[String],
+        ) -> Result<Option<VersionTag>, Box<dyn Error>> {
+            self.get_latest_version_tag_with_prefixes(prefixes)
+        }
+
+        fn all_version_tags_with_prefixes(
+            &self,
+            prefixes: &[String],
+        ) -> Result<Vec<VersionTag>, Box<dyn Error>> {
+            Ok(self
+                .get_latest_version_tag_with_prefixes(prefixes)?
+                .into_iter()
+                .collect())
+        }
+
+        fn all_version_tags_with_scheme(
+            &self,
+            prefixes: &[String],
+            _scheme: VersionScheme,
+        ) -> Result<Vec<VersionTag>, Box<dyn Error>> {
+            self.all_version_tags_with_prefixes(prefixes)
+        }
+
+        fn find_duplicate_version_tags(
+            &self,
+            _prefixes: &[String],
+        ) -> Result<Vec<DuplicateVersionTag>, Box<dyn Error>> {
+            Ok(Vec::new())
+        }
+
+        fn find_malformed_version_tags(
+            &self,
+            _prefixes: &[String],
+        ) -> Result<Vec<String>, Box<dyn Error>> {
+            Ok(Vec::new())
+        }
+
+        fn default_branch(&self) -> Result<String, Box<dyn Error>> {
+            Ok("main".to_string())
+        }
+
+        fn is_clean(&self) -> Result<bool, Box<dyn Error>> {
+            Ok(true)
+        }
+    }
+
+    impl MockedRepository {
+        fn from_commits(commits: Vec<&str>) -> Self {
+            Self {
+                commits: convert(commits),
+                commit_fetching_fails: false,
+                commit_with_latest_tag: None,
+                latest_version_tag: None,
+                tag_fetching_fails: false,
+            }
+        }
+
+        fn new() -> Self {
+            Self {
+                commits: Vec::new(),
+                commit_fetching_fails: false,
+                commit_with_latest_tag: None,
+                latest_version_tag: None,
+                tag_fetching_fails: false,
+            }
+        }
+    }
+
+    #[test]
+    fn creating_from_empty_commit_list() {
+        // Given
+        let repository = MockedRepository::new();
+
+        // When
+        let result = Changes::from_repo(&repository).unwrap();
+
+        // Then
+        let expected_result = Changes {
+            major: Vec::new(),
+            minor: Vec::new(),
+            patch: Vec::new(),
+            other: Vec::new(),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn error_during_fetching_commits() {
+        // Given
+        let mut repository = MockedRepository::new();
+        repository.commit_fetching_fails = true;
+
+        // When
+        let result = Changes::from_repo(&repository);
+
+        // Then
+        assert!(result.is_err(), "Expected error, but got Ok");
+    }
+
+    #[test]
+    fn calling_back_once_per_analyzed_commit() {
+        // Given
+        let commit_messages = vec![
+            ":boom: remove the old API",
+            ":sparkles: add feature",
+            "update the readme",
+        ];
+        let repository = MockedRepository::from_commits(commit_messages.clone());
+
+        // When
+        let mut visited = Vec::new();
+        let result =
+            Changes::from_repo_with(&repository, |commit| visited.push(commit.message.clone()))
+                .unwrap();
+
+        // Then
+        assert_eq!(visited, commit_messages);
+        assert_eq!(result, Changes::from_repo(&repository).unwrap());
+    }
+
+    #[test]
+    fn creating_from_only_major_conventional_commits() {
+        // Given
+        let commit_messages = vec!["💥 introduce breaking changes"];
+        let repository = MockedRepository::from_commits(commit_messages.clone());
+
+        // When
+        let result = Changes::from_repo(&repository).unwrap();
+
+        // Then
+        let expected_result = Changes {
+            major: convert(commit_messages),
+            minor: Vec::new(),
+            patch: Vec::new(),
+            other: Vec::new(),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn creating_from_only_minor_conventional_commits() {
+        // Given
+        let commit_messages = vec![
+            ":sparkles: introduce new feature",
+            ":children_crossing: improve user experience / usability",
+            "💄 add or update the UI and style files",
+            ":iphone: work on responsive design",
+            ":egg: add or update an easter egg",
+            ":chart_with_upwards_trend: add or update analytics or track code",
+            ":heavy_plus_sign: add a dependency",
+            ":heavy_minus_sign: remove a dependency",
+            ":passport_control: work on code related to authorization, roles and permissions",
+        ];
+        let repository = MockedRepository::from_commits(commit_messages.clone());
+
+        // When
+        let result = Changes::from_repo(&repository).unwrap();
+
+        // Then
+        let expected_result = Changes {
+            major: Vec::new(),
+            minor: convert(commit_messages),
+            patch: Vec::new(),
+            other: Vec::new(),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn creating_from_only_patch_conventional_commits() {
+        // Given
+        let commit_messages = vec![
+            ":art: improve structure / format of the code",
+            ":ambulance: critical hotfix",
+            ":lock: fix security or privacy issues",
+            "🐛 fix a bug",
+            ":zap: improve performance",
+            ":goal_net: catch errors",
+            ":alien: update code due to external API changes",
+            ":wheelchair: improve accessibility",
+            ":speech_balloon: add or update text and literals",
+            ":mag: improve SEO",
+            ":fire: remove code or files",
+            ":white_check_mark: add, update, or pass tests",
+            ":closed_lock_with_key: add or update secrets",
+            ":rotating_light: fix compiler / linter warnings",
+            ":green_heart: fix CI build",
+            ":arrow_down: downgrade dependencies",
+            ":arrow_up: upgrade dependencies",
+            ":pushpin: pin dependencies to specific versions",
+            ":construction_worker: add or update CI build system",
+            ":recycle: refactor code",
+            ":wrench: add or update configuration files",
+            ":hammer: add or update development scripts",
+            ":globe_with_meridians: internationalization and localization",
+            ":package: add or update compiled files or packages",
+            ":truck: move or rename resources (e.g.: files, paths, routes",
+            ":bento: add or update assets",
+            ":card_file_box: perform database related changes",
+            ":loud_sound: add or update logs",
+            ":mute: remove logs",
+            ":building_construction: make architectural changes",
+            ":camera_flash: add or update snapshots",
+            ":label: add or update types",
+            ":seedling: add or update seed files",
+            ":triangular_flag_on_post: add, update, or remove feature flags",
+            ":dizzy: add or update animations an transitions",
+            ":adhesive_bandage: simple fix for a non critical issue",
+            ":monocle_face: data exploration / inspection",
+            ":necktie: add or update business logic",
+            ":stethoscope: add or update healthcheck",
+            ":technologist: improve developer experience",
+            ":thread: add or update code related to multithreading or concurrency",
+            ":safety_vest: add or update code related to validation",
+        ];
+        let repository = MockedRepository::from_commits(commit_messages.clone());
+
+        // When
+        let result = Changes::from_repo(&repository).unwrap();
+
+        // Then
+        let expected_result = Changes {
+            major: Vec::new(),
+            minor: Vec::new(),
+            patch: convert(commit_messages),
+            other: Vec::new(),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn creating_from_only_other_conventional_commits() {
+        let commit_messages = vec![
+            ":memo: add or update documentation",
+            ":rocket: deploy stuff",
+            ":tada: begin a project",
+            ":bookmark: release / version tags",
+            ":construction: work in progress",
+            ":pencil2: fix typos",
+            ":poop: write bad code that needs to be improved",
+            ":rewind: revert changes",
+            ":twisted_rightwards_arrows: merge branches",
+            ":page_facing_up: add or update license",
+            ":bulb: add or update comments in source code",
+            "🍻 write code drunkenly",
+            ":bust_in_silhouette: add or update contributor(s)",
+            ":clown_face: mock things",
+            ":see_no_evil: add or update a .gitignore file",
+            ":alembic: perform experiments",
+            ":wastebasket: deprecate code that needs to be cleaned up",
+            ":coffin: remove dead code",
+            ":test_tube: add a failing test",
+            ":bricks: infrastructure related changes",
+            ":money_with_wings: add sponsorship or money related infrastructure",
+        ];
+        let repository = MockedRepository::from_commits(commit_messages.clone());
+
+        // When
+        let result = Changes::from_repo(&repository).unwrap();
+
+        // Then
+        let expected_result = Changes {
+            major: Vec::new(),
+            minor: Vec::new(),
+            patch: Vec::new(),
+            other: convert(commit_messages),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn creating_with_custom_intentions() {
+        // Given
+        let commit_messages = vec![
+            ":sparkling_heart: add sponsors",
+            ":memo: add or update documentation",
+            "plain commit",
+        ];
+        let repository = MockedRepository::from_commits(commit_messages.clone());
+        let mut intention_map = IntentionMap::default();
+        intention_map
+            .register(":sparkling_heart:", "💖", Category::Minor)
+            .unwrap();
+
+        // When
+        let result = Changes::from_repo_with_intentions(
+            &repository,
+            &FetchOptions::default(),
+            &intention_map,
+        )
+        .unwrap();
+
+        // Then
+        let expected_result = Changes {
+            major: Vec::new(),
+            minor: convert(vec![commit_messages[0]]),
+            patch: Vec::new(),
+            other: convert(vec![commit_messages[1]]),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn creating_from_commits() {
+        // Given
+        let commits = vec![
+            ConventionalCommit {
+                message: ":sparkles: add feature".to_string(),
+                hash: "3c8e19bf7fe7ba3dd6f0489374a5200fad124377".to_string(),
+                ..Default::default()
+            },
+            ConventionalCommit {
+                message: ":green_heart: fix CI build".to_string(),
+                hash: "9d5f3a1b2c4e6f7a8b9c0d1e2f3a4b5c6d7e8f90".to_string(),
+                ..Default::default()
+            },
+            ConventionalCommit {
+                message: "plain commit".to_string(),
+                hash: "0a1b2c3d4e5f6a7b8c9d0e1f2a3b4c5d6e7f8a9b".to_string(),
+                ..Default::default()
+            },
+        ];
+
+        // When
+        let result = Changes::from_commits(commits.clone());
+
+        // Then
+        let expected_result = Changes {
+            major: Vec::new(),
+            minor: vec![commits[0].clone()],
+            patch: vec![commits[1].clone()],
+            other: Vec::new(),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn creating_from_git2_commits() {
+        // Given
+        let commit_messages = vec![":boom: remove the old API", ":memo: update the readme"];
+        let (_temp_dir, repository) = repo_init(Some(commit_messages.clone()));
+        let commits = commit_messages
+            .iter()
+            .map(|message| repository.find_commit_by_message(message).unwrap())
+            .collect();
+
+        // When
+        let result = Changes::from_git2_commits(commits);
+
+        // Then
+        assert_eq!(result.counts().major, 1);
+        assert_eq!(result.counts().other, 1);
+    }
+
+    #[test]
+    fn creating_from_messages() {
+        // Given
+        let messages = vec![
+            ":boom: remove the old API".to_string(),
+            "".to_string(),
+            "   ".to_string(),
+            "plain commit".to_string(),
+            "🐛 fix the parser".to_string(),
+        ];
+
+        // When
+        let result = Changes::from_messages(messages);
+
+        // Then
+        let expected_result = Changes {
+            major: convert(vec![":boom: remove the old API"]),
+            minor: Vec::new(),
+            patch: convert(vec!["🐛 fix the parser"]),
+            other: Vec::new(),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn keeping_unrecognized_commits_as_other_changes() {
+        // Given
+        let messages = vec![
+            "plain commit".to_string(),
+            ":sparkles: add feature".to_string(),
+        ];
+        let mut intention_map = IntentionMap::default();
+        intention_map.set_keep_unrecognized(true);
+
+        // When
+        let result = Changes::from_messages_with_intentions(messages, &intention_map);
+
+        // Then
+        let expected_result = Changes {
+            minor: convert(vec![":sparkles: add feature"]),
+            other: convert(vec!["plain commit"]),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn creating_from_limited_number_of_commits() {
+        // Given
+        let commit_messages: Vec<String> = (1..=10)
+            .map(|index| format!(":bug: fix bug {index}"))
+            .collect();
+        let (_temp_dir, repository) =
+            repo_init(Some(commit_messages.iter().map(String::as_str).collect()));
+
+        // When
+        let result = Changes::from_repo_limited(&repository, 3).unwrap();
+
+        // Then
+        assert_eq!(result.counts().patch, 3);
+    }
+
+    #[test]
+    fn analyzing_repository() {
+        // Given
+        let commit_messages = vec![
+            ":sparkles: introduce new feature",
+            ":green_heart: fix CI build",
+            ":memo: add or update documentation",
+        ];
+        let repository = MockedRepository::from_commits(commit_messages);
+
+        // When
+        let (changes, action) = analyze(&repository).unwrap();
+
+        // Then
+        let expected_changes = Changes::from_repo(&repository).unwrap();
+        assert_eq!(changes, expected_changes);
+        assert_eq!(
+            action,
+            expected_changes.define_action_for_semantic_version()
+        );
+    }
+
+    #[test]
+    fn creating_from_repo_with_tags() {
+        // Given
+        let commit_messages = vec![
+            "💥 introduce breaking changes",
+            ":sparkles: introduce new feature",
+            ":money_with_wings: add sponsorship or money related infrastructure",
+            ":memo: add or update documentation",
+        ];
+        let mut repository = MockedRepository::from_commits(commit_messages.clone());
+        repository.latest_version_tag = Some(VersionTag {
+            version: Version::new(1, 0, 0),
+            commit_oid: Oid::zero(),
+            commit_time: 0,
+            kind: TagKind::Annotated,
+        });
+        repository.commit_with_latest_tag = Some(commit_messages[1].into());
+
+        // When
+        let result = Changes::from_repo(&repository).unwrap();
+
+        // Then
+        let expected_result = Changes {
+            major: Vec::new(),
+            minor: Vec::new(),
+            patch: Vec::new(),
+            other: convert(commit_messages[2..].to_vec()),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn error_during_fetching_latest_tag() {
+        // Given
+        let commit_messages = vec![
+            ":sparkles: introduce new feature",
+            ":children_crossing: improve user experience / usability",
+            "💄 add or update the UI and style files",
+            ":iphone: work on responsive design",
+            ":egg: add or update an easter egg",
+            ":chart_with_upwards_trend: add or update analytics or track code",
+            ":heavy_plus_sign: add a dependency",
+            ":heavy_minus_sign: remove a dependency",
+            ":passport_control: work on code related to authorization, roles and permissions",
+        ];
+        let mut repository = MockedRepository::from_commits(commit_messages.clone());
+        repository.tag_fetching_fails = true;
+
+        // When
+        let result = Changes::from_repo(&repository);
+
+        // Then
+        assert!(result.is_err(), "Expected Error, got Ok");
+    }
+
+    #[test]
+    fn creating_with_try_from() {
+        // Given
+        let commit_messages = vec!["💥 introduce breaking changes"];
+        let (_temp_dir, repository) = repo_init(Some(commit_messages.clone()));
+        let commit = repository
+            .find_commit_by_message("💥 introduce breaking changes")
+            .unwrap();
+
+        // When
+        let result = Changes::try_from(&repository).unwrap();
+
+        // Then
+        let expected_result = Changes {
+            major: vec![ConventionalCommit {
+                message: commit.message().unwrap().to_string(),
+                hash: commit.id().to_string(),
+                author_name: "name".to_string(),
+                author_email: "email".to_string(),
+                author_time: commit.author().when().seconds(),
+                author_offset: commit.author().when().offset_minutes(),
+            }],
+            minor: Vec::new(),
+            patch: Vec::new(),
+            other: Vec::new(),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn try_from_empty_repo() {
+        // Given
+        let (_temp_dir, repository) = repo_init(None);
+
+        // When
+        let result = Changes::try_from(&repository);
+
+        // Then
+        assert!(
+            matches!(result, Err(ChangesError::NoCommits)),
+            "Expected NoCommits error, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn try_from_repo_with_unborn_head() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec!["💥 introduce breaking changes"]));
+        repository.set_head("refs/heads/unborn").unwrap();
+
+        // When
+        let result = Changes::try_from(&repository);
+
+        // Then
+        assert!(
+            matches!(result, Err(ChangesError::NoCommits)),
+            "Expected NoCommits error, got {result:?}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod merge_changes_tests {
+    use crate::changes::Changes;
+    use crate::repo::ConventionalCommit;
+
+    fn convert(messages: Vec<&str>) -> Vec<ConventionalCommit> {
+        messages
+            .iter()
+            .map(|commit_message| ConventionalCommit {
+                message: commit_message.to_string(),
+                hash: "".to_string(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn default_is_empty() {
+        // When
+        let result = Changes::default();
+
+        // Then
+        let expected_result = Changes {
+            major: Vec::new(),
+            minor: Vec::new(),
+            patch: Vec::new(),
+            other: Vec::new(),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn clone_is_equal() {
+        // Given
+        let changes = Changes {
+            major: convert(vec!["💥 introduce breaking changes"]),
+            minor: convert(vec!["✨ add feature"]),
+            patch: Vec::new(),
+            other: convert(vec!["📝 add documentation"]),
+            ..Default::default()
+        };
+
+        // When
+        let result = changes.clone();
+
+        // Then
+        assert_eq!(result, changes);
+    }
+
+    #[test]
+    fn merging_partial_changes() {
+        // Given
+        let mut changes = Changes {
+            major: convert(vec!["💥 introduce breaking changes"]),
+            minor: convert(vec!["✨ add feature"]),
+            ..Default::default()
+        };
+        let other_changes = Changes {
+            minor: convert(vec!["✨ add another feature"]),
+            patch: convert(vec!["🐛 fix bug"]),
+            ..Default::default()
+        };
+
+        // When
+        changes.merge(other_changes);
+
+        // Then
+        let expected_result = Changes {
+            major: convert(vec!["💥 introduce breaking changes"]),
+            minor: convert(vec!["✨ add feature", "✨ add another feature"]),
+            patch: convert(vec!["🐛 fix bug"]),
+            other: Vec::new(),
+            ..Default::default()
+        };
+        assert_eq!(changes, expected_result);
+    }
+
+    #[test]
+    fn difference_of_overlapping_changes() {
+        // Given
+        let changes = Changes {
+            minor: convert(vec!["✨ add feature", "✨ add another feature"]),
+            patch: convert(vec!["🐛 fix bug"]),
+            other: convert(vec!["📝 add documentation"]),
+            ..Default::default()
+        };
+        let previous_changes = Changes {
+            minor: convert(vec!["✨ add feature"]),
+            other: convert(vec!["📝 add documentation"]),
+            ..Default::default()
+        };
+
+        // When
+        let result = changes.difference(&previous_changes);
+
+        // Then
+        let expected_result = Changes {
+            minor: convert(vec!["✨ add another feature"]),
+            patch: convert(vec!["🐛 fix bug"]),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn difference_of_same_changes_is_empty() {
+        // Given
+        let changes = Changes {
+            major: convert(vec!["💥 introduce breaking changes"]),
+            patch: convert(vec!["🐛 fix bug"]),
+            ..Default::default()
+        };
+
+        // When
+        let result = changes.difference(&changes.clone());
+
+        // Then
+        assert_eq!(result, Changes::default());
+    }
+
+    #[test]
+    fn changes_with_different_other_commits_are_not_equal() {
+        // Given
+        let changes = Changes {
+            minor: convert(vec!["✨ add feature"]),
+            other: convert(vec!["📝 add documentation"]),
+            ..Default::default()
+        };
+        let other_changes = Changes {
+            minor: convert(vec!["✨ add feature"]),
+            other: convert(vec!["📝 update documentation"]),
+            ..Default::default()
+        };
+
+        // Then
+        assert_ne!(changes, other_changes);
+    }
+
+    #[test]
+    fn changes_with_duplicate_commits_are_not_equal_to_deduplicated_changes() {
+        // Given
+        let changes = Changes {
+            patch: convert(vec!["🐛 fix bug", "🐛 fix bug", "🐛 fix another bug"]),
+            ..Default::default()
+        };
+        let deduplicated_changes = Changes {
+            patch: convert(vec!["🐛 fix bug", "🐛 fix another bug"]),
+            ..Default::default()
+        };
+        let reordered_changes = Changes {
+            patch: convert(vec!["🐛 fix another bug", "🐛 fix bug", "🐛 fix bug"]),
+            ..Default::default()
+        };
+
+        // Then
+        assert_ne!(changes, deduplicated_changes);
+        assert_ne!(deduplicated_changes, changes);
+        assert_eq!(changes, reordered_changes);
+    }
+}
+
+#[cfg(test)]
+mod submodule_tests {
+    use crate::changes::Changes;
+    use crate::intention::{Category, IntentionMap};
+    use crate::repo::FetchOptions;
+    use crate::test_util::{repo_init, RepositoryTestExtensions};
+    use std::path::Path;
+
+    #[test]
+    fn creating_from_repo_with_submodule() {
+        // Given
+        let (submodule_dir, _submodule_repository) = repo_init(Some(vec![
+            ":tada: initial commit",
+            ":sparkles: add feature to the submodule",
+        ]));
+        let (_temp_dir, repository) = repo_init(Some(vec![":bug: fix bug"]));
+        let mut submodule = repository
+            .submodule(
+                submodule_dir.path().to_str().unwrap(),
+                Path::new("libs/core"),
+                true,
+            )
+            .unwrap();
+        submodule.clone(None).unwrap();
+        submodule.add_finalize().unwrap();
+        repository.add_commit(":heavy_plus_sign: add submodule");
+
+        // When
+        let result = Changes::from_repo_recursive(
+            &repository,
+            &FetchOptions::default(),
+            &IntentionMap::default(),
+        )
+        .unwrap();
+
+        // Then
+        let counts = result.counts();
+        assert_eq!(counts.minor, 2);
+        assert_eq!(counts.patch, 1);
+        assert!(
+            result
+                .commits(Category::Minor)
+                .iter()
+                .any(
+                    |commit| commit.message() == ":sparkles: add feature to the submodule"
+                        && commit.namespace() == "libs/core"
+                ),
+            "submodule commit is not namespaced: {result:?}"
+        );
+    }
+
+    #[test]
+    fn namespacing_hashes() {
+        // Given
+        let mut changes = Changes::from_messages(vec![":bug: fix bug".to_string()]);
+
+        // When
+        changes.namespace_hashes("core");
+        changes.namespace_hashes("libs");
+
+        // Then
+        let commit = &changes.commits(Category::Patch)[0];
+        assert_eq!(commit.namespace(), "libs/core");
+    }
+}
+
+#[cfg(test)]
+mod trigger_tests {
+    use crate::changes::Changes;
+    use crate::intention::Category;
+
+    #[test]
+    fn major_commit_triggers_the_action() {
+        // Given
+        let changes = Changes::from_messages(vec![
+            ":sparkles: add feature".to_string(),
+            ":boom: remove the old API".to_string(),
+            ":boom: rename the config file".to_string(),
+        ]);
+
+        // When
+        let (commit, category) = changes.trigger().unwrap();
+
+        // Then
+        assert_eq!(commit.message, ":boom: remove the old API");
+        assert_eq!(category, Category::Major);
+    }
+
+    #[test]
+    fn minor_commit_triggers_the_action_without_major_commits() {
+        // Given
+        let changes = Changes::from_messages(vec![
+            ":bug: fix the parser".to_string(),
+            ":sparkles: add feature".to_string(),
+        ]);
+
+        // When
+        let (commit, category) = changes.trigger().unwrap();
+
+        // Then
+        assert_eq!(commit.message, ":sparkles: add feature");
+        assert_eq!(category, Category::Minor);
+    }
+
+    #[test]
+    fn iterating_over_changes() {
+        // Given
+        let changes = Changes::from_messages(vec![
+            ":memo: update docs".to_string(),
+            ":bug: fix the parser".to_string(),
+            ":sparkles: add feature".to_string(),
+            ":bug: fix the printer".to_string(),
+        ]);
+
+        // When
+        let result: Vec<_> = (&changes)
+            .into_iter()
+            .map(|(category, commit)| (category, commit.message.as_str()))
+            .collect();
+
+        // Then
+        assert_eq!(
+            result,
+            vec![
+                (Category::Minor, ":sparkles: add feature"),
+                (Category::Patch, ":bug: fix the parser"),
+                (Category::Patch, ":bug: fix the printer"),
+                (Category::Other, ":memo: update docs"),
+            ]
+        );
+    }
+
+    #[test]
+    fn iterating_over_owned_changes() {
+        // Given
+        let changes = Changes::from_messages(vec![
+            ":bug: fix the parser".to_string(),
+            ":boom: remove the old API".to_string(),
+        ]);
+
+        // When
+        let result: Vec<_> = changes
+            .into_iter()
+            .map(|(category, commit)| (category, commit.message))
+            .collect();
+
+        // Then
+        assert_eq!(
+            result,
+            vec![
+                (Category::Major, ":boom: remove the old API".to_string()),
+                (Category::Patch, ":bug: fix the parser".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn getting_commits_of_a_category() {
+        // Given
+        let changes = Changes::from_messages(vec![
+            ":bug: fix the parser".to_string(),
+            ":sparkles: add feature".to_string(),
+        ]);
+
+        // When
+        let result = changes.commits(Category::Patch);
+
+        // Then
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].message, ":bug: fix the parser");
+    }
+
+    #[test]
+    fn nothing_triggers_the_action_without_changes() {
+        // Given
+        let changes = Changes::default();
+
+        // When
+        let result = changes.trigger();
+
+        // Then
+        assert!(result.is_none(), "Expected None, but got Some");
+    }
+}
+
+#[cfg(test)]
+mod change_counts_tests {
+    use crate::changes::{ChangeCounts, Changes};
+    use crate::repo::ConventionalCommit;
+
+    fn convert(messages: Vec<&str>) -> Vec<ConventionalCommit> {
+        messages
+            .iter()
+            .map(|commit_message| ConventionalCommit {
+                message: commit_message.to_string(),
+                hash: "".to_string(),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn counting_commits_by_scope() {
+        // Given
+        let changes = Changes {
+            major: convert(vec!["💥 (parser): drop old syntax"]),
+            minor: convert(vec![
+                "✨ (parser): add new syntax",
+                "✨ (cli): add --verbose flag",
+            ]),
+            patch: convert(vec!["🐛 (cli): fix argument parsing", "♻️ refactor code"]),
+            other: convert(vec!["📝 add documentation"]),
+            ..Default::default()
+        };
+
+        // When
+        let result = changes.counts_by_scope();
+
+        // Then
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result[""],
+            ChangeCounts {
+                major: 0,
+                minor: 0,
+                patch: 1,
+                other: 1
+            }
+        );
+        assert_eq!(
+            result["cli"],
+            ChangeCounts {
+                major: 0,
+                minor: 1,
+                patch: 1,
+                other: 0
+            }
+        );
+        assert_eq!(
+            result["parser"],
+            ChangeCounts {
+                major: 1,
+                minor: 1,
+                patch: 0,
+                other: 0
+            }
+        );
+        assert_eq!(
+            changes.counts(),
+            ChangeCounts {
+                major: 1,
+                minor: 2,
+                patch: 2,
+                other: 1
+            }
+        );
+    }
+
+    #[test]
+    fn table_rows_align_multi_digit_counts() {
+        // Given
+        let counts = ChangeCounts {
+            major: 1,
+            minor: 12,
+            patch: 345,
+            other: 0,
+        };
+
+        // When
+        let rows = counts.table_rows();
+
+        // Then
+        let rows: Vec<&str> = rows.iter().map(|(_, row)| row.as_str()).collect();
+        assert_eq!(
+            rows,
+            vec!["major   1", "minor  12", "patch 345", "other   0"]
+        );
+    }
+}
+
+#[cfg(test)]
+mod contributors_tests {
+    use crate::changes::{Changes, SemanticVersionAction};
+    use crate::intention::Category;
+    use crate::repo::ConventionalCommit;
+
+    fn commit_by(author_name: &str, author_email: &str) -> ConventionalCommit {
+        ConventionalCommit {
+            message: ":sparkles: add feature".to_string(),
+            hash: "".to_string(),
+            author_name: author_name.to_string(),
+            author_email: author_email.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn collecting_unique_contributors() {
+        // Given
+        let changes = Changes {
+            major: vec![commit_by("Jan", "jan@example.com")],
+            minor: vec![
+                commit_by("Kristof", "kristof@example.com"),
+                commit_by("Jan", "jan@example.com"),
+            ],
+            patch: Vec::new(),
+            other: vec![commit_by("Kristof", "kristof@example.com")],
+            ..Default::default()
+        };
+
+        // When
+        let result = changes.contributors();
+
+        // Then
+        assert_eq!(
+            result,
+            vec![
+                ("Jan".to_string(), "jan@example.com".to_string()),
+                ("Kristof".to_string(), "kristof@example.com".to_string()),
+            ]
+        );
+    }
+    #[test]
+    fn excluding_bot_author() {
+        // Given
+        let mut changes = Changes {
+            minor: vec![commit_by(
+                "dependabot[bot]",
+                "49699333+dependabot[bot]@users.noreply.github.com",
+            )],
+            patch: vec![commit_by("Jan", "jan@example.com")],
+            ..Default::default()
+        };
+
+        // When
+        changes.exclude_authors(&["dependabot[bot]".to_string()]);
+
+        // Then
+        assert!(changes.commits(Category::Minor).is_empty());
+        assert_eq!(
+            changes.define_action_for_semantic_version(),
+            SemanticVersionAction::IncrementPatch
+        );
+    }
+
+    #[test]
+    fn excluding_authors_by_plain_text() {
+        // Given
+        let mut changes = Changes {
+            minor: vec![
+                commit_by("dependabot[bot]", "support@github.com"),
+                commit_by("dependabott", "dependabott@example.com"),
+                commit_by("renovate-bot", "bot@renovateapp.com"),
+            ],
+            ..Default::default()
+        };
+
+        // When
+        changes.exclude_authors(&["dependabot[bot]".to_string(), "^renovate".to_string()]);
+
+        // Then
+        assert_eq!(
+            changes.contributors(),
+            vec![
+                (
+                    "dependabott".to_string(),
+                    "dependabott@example.com".to_string()
+                ),
+                (
+                    "renovate-bot".to_string(),
+                    "bot@renovateapp.com".to_string()
+                ),
+            ]
+        );
     }
 
-    impl MockedRepository {
-        fn from_commits(commits: Vec<&str>) -> Self {
-            Self {
-                commits: convert(commits),
-                commit_fetching_fails: false,
-                commit_with_latest_tag: None,
-                latest_version_tag: None,
-                tag_fetching_fails: false,
-            }
-        }
+    #[test]
+    fn excluding_unrecognized_commits_of_author() {
+        // Given
+        let mut changes = Changes {
+            unrecognized: vec![ConventionalCommit {
+                message: "Bump serde from 1.0.0 to 1.0.1".to_string(),
+                ..commit_by(
+                    "dependabot[bot]",
+                    "dependabot[bot]@users.noreply.github.com",
+                )
+            }],
+            ..Default::default()
+        };
 
-        fn new() -> Self {
-            Self {
-                commits: Vec::new(),
-                commit_fetching_fails: false,
-                commit_with_latest_tag: None,
-                latest_version_tag: None,
-                tag_fetching_fails: false,
-            }
-        }
+        // When
+        changes.exclude_authors(&["dependabot[bot]".to_string()]);
+
+        // Then
+        assert!(changes.unrecognized().is_empty());
     }
+}
+
+#[cfg(test)]
+mod next_version_tests {
+    use crate::changes::{validate_monotonic, SemanticVersionAction};
+    use semver::Version;
 
     #[test]
-    fn creating_from_empty_commit_list() {
+    fn ordering_actions() {
+        assert!(SemanticVersionAction::Keep < SemanticVersionAction::IncrementPatch);
+        assert!(SemanticVersionAction::IncrementPatch < SemanticVersionAction::IncrementMinor);
+        assert!(SemanticVersionAction::IncrementMinor < SemanticVersionAction::IncrementMajor);
+    }
+
+    #[test]
+    fn highest_action_of_multiple_analyses() {
         // Given
-        let repository = MockedRepository::new();
+        let actions = vec![
+            SemanticVersionAction::IncrementPatch,
+            SemanticVersionAction::IncrementMinor,
+            SemanticVersionAction::Keep,
+        ];
 
         // When
-        let result = Changes::from_repo(&repository).unwrap();
+        let result = actions.into_iter().max();
 
         // Then
-        let expected_result = Changes {
-            major: Vec::new(),
-            minor: Vec::new(),
-            patch: Vec::new(),
-            other: Vec::new(),
-        };
-        assert_eq!(result, expected_result);
+        assert_eq!(result, Some(SemanticVersionAction::IncrementMinor));
     }
 
     #[test]
-    fn error_during_fetching_commits() {
+    fn starting_pre_release() {
         // Given
-        let mut repository = MockedRepository::new();
-        repository.commit_fetching_fails = true;
+        let current = Version::new(1, 2, 3);
 
         // When
-        let result = Changes::from_repo(&repository);
+        let result = SemanticVersionAction::IncrementMinor
+            .next_pre_release(&current, "rc")
+            .unwrap();
 
         // Then
-        assert!(result.is_err(), "Expected error, but got Ok");
+        assert_eq!(result, Version::parse("1.3.0-rc.1").unwrap());
+        assert!(
+            validate_monotonic(&current, &result, &SemanticVersionAction::IncrementMinor).is_ok()
+        );
     }
 
     #[test]
-    fn creating_from_only_major_conventional_commits() {
+    fn incrementing_pre_release_counter() {
         // Given
-        let commit_messages = vec!["💥 introduce breaking changes"];
-        let repository = MockedRepository::from_commits(commit_messages.clone());
+        let current = Version::parse("1.3.0-rc.1").unwrap();
 
         // When
-        let result = Changes::from_repo(&repository).unwrap();
+        let result = SemanticVersionAction::IncrementMinor
+            .next_pre_release(&current, "rc")
+            .unwrap();
 
         // Then
-        let expected_result = Changes {
-            major: convert(commit_messages),
-            minor: Vec::new(),
-            patch: Vec::new(),
-            other: Vec::new(),
-        };
-        assert_eq!(result, expected_result);
+        assert_eq!(result, Version::parse("1.3.0-rc.2").unwrap());
     }
 
     #[test]
-    fn creating_from_only_minor_conventional_commits() {
+    fn switching_pre_release_identifier() {
         // Given
-        let commit_messages = vec![
-            ":sparkles: introduce new feature",
-            ":children_crossing: improve user experience / usability",
-            "💄 add or update the UI and style files",
-            ":iphone: work on responsive design",
-            ":egg: add or update an easter egg",
-            ":chart_with_upwards_trend: add or update analytics or track code",
-            ":heavy_plus_sign: add a dependency",
-            ":heavy_minus_sign: remove a dependency",
-            ":passport_control: work on code related to authorization, roles and permissions",
+        let current = Version::parse("1.3.0-beta.4").unwrap();
+
+        // When
+        let result = SemanticVersionAction::IncrementPatch
+            .next_pre_release(&current, "rc")
+            .unwrap();
+
+        // Then
+        assert_eq!(result, Version::parse("1.3.1-rc.1").unwrap());
+    }
+
+    #[test]
+    fn invalid_pre_release_identifier() {
+        // When
+        let result =
+            SemanticVersionAction::IncrementPatch.next_pre_release(&Version::new(1, 2, 3), "r c");
+
+        // Then
+        assert!(result.is_err(), "Expected an error, but got {result:?}");
+    }
+
+    #[test]
+    fn incrementing_versions() {
+        // Given
+        let current = Version::parse("1.2.3-rc.1+build.5").unwrap();
+        let expected_versions = [
+            (SemanticVersionAction::IncrementMajor, "2.0.0"),
+            (SemanticVersionAction::IncrementMinor, "1.3.0"),
+            (SemanticVersionAction::IncrementPatch, "1.2.4"),
+            (SemanticVersionAction::Keep, "1.2.3-rc.1+build.5"),
         ];
-        let repository = MockedRepository::from_commits(commit_messages.clone());
+
+        for (action, expected_version) in expected_versions {
+            // When
+            let result = action.next_version(&current);
+
+            // Then
+            assert_eq!(result, Version::parse(expected_version).unwrap());
+            assert!(
+                validate_monotonic(&current, &result, &action).is_ok(),
+                "{result} is not a valid next version for {action}"
+            );
+        }
+    }
+
+    #[test]
+    fn validating_non_increasing_version() {
+        // Given
+        let current = Version::new(1, 2, 3);
+        let next = Version::new(1, 2, 0);
 
         // When
-        let result = Changes::from_repo(&repository).unwrap();
+        let result = validate_monotonic(&current, &next, &SemanticVersionAction::IncrementPatch);
 
         // Then
-        let expected_result = Changes {
+        assert!(result.is_err(), "Expected an error, but got Ok");
+    }
+
+    #[test]
+    fn validating_changed_version_when_kept() {
+        // Given
+        let current = Version::new(1, 2, 3);
+        let next = Version::new(1, 2, 4);
+
+        // When
+        let result = validate_monotonic(&current, &next, &SemanticVersionAction::Keep);
+
+        // Then
+        assert!(result.is_err(), "Expected an error, but got Ok");
+    }
+}
+
+#[cfg(test)]
+mod evaluate_changes_tests {
+    use crate::changes::{ActionOptions, Changes, SemanticVersionAction, ZeroVerMode};
+    use crate::repo::ConventionalCommit;
+    use semver::Version;
+
+    fn documentation_only_changes() -> Changes {
+        Changes {
             major: Vec::new(),
-            minor: convert(commit_messages),
+            minor: Vec::new(),
             patch: Vec::new(),
-            other: Vec::new(),
+            other: vec![
+                ConventionalCommit {
+                    message: ":memo: add documentation".to_string(),
+                    hash: "".to_string(),
+                    ..Default::default()
+                },
+                ConventionalCommit {
+                    message: "✏️ fix typos".to_string(),
+                    hash: "".to_string(),
+                    ..Default::default()
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn has_documentation_changes_triggering_patch() {
+        // Given
+        let changes = documentation_only_changes();
+        let options = ActionOptions {
+            docs_trigger_patch: true,
+            ..Default::default()
         };
-        assert_eq!(result, expected_result);
+
+        // When
+        let result = changes.define_action_with_options(&options);
+
+        // Then
+        assert_eq!(result, SemanticVersionAction::IncrementPatch);
     }
 
     #[test]
-    fn creating_from_only_patch_conventional_commits() {
+    fn has_documentation_and_other_changes_not_triggering_patch() {
         // Given
-        let commit_messages = vec![
-            ":art: improve structure / format of the code",
-            ":ambulance: critical hotfix",
-            ":lock: fix security or privacy issues",
-            "🐛 fix a bug",
-            ":zap: improve performance",
-            ":goal_net: catch errors",
-            ":alien: update code due to external API changes",
-            ":wheelchair: improve accessibility",
-            ":speech_balloon: add or update text and literals",
-            ":mag: improve SEO",
-            ":fire: remove code or files",
-            ":white_check_mark: add, update, or pass tests",
-            ":closed_lock_with_key: add or update secrets",
-            ":rotating_light: fix compiler / linter warnings",
-            ":green_heart: fix CI build",
-            ":arrow_down: downgrade dependencies",
-            ":arrow_up: upgrade dependencies",
-            ":pushpin: pin dependencies to specific versions",
-            ":construction_worker: add or update CI build system",
-            ":recycle: refactor code",
-            ":wrench: add or update configuration files",
-            ":hammer: add or update development scripts",
-            ":globe_with_meridians: internationalization and localization",
-            ":package: add or update compiled files or packages",
-            ":truck: move or rename resources (e.g.: files, paths, routes",
-            ":bento: add or update assets",
-            ":card_file_box: perform database related changes",
-            ":loud_sound: add or update logs",
-            ":mute: remove logs",
-            ":building_construction: make architectural changes",
-            ":camera_flash: add or update snapshots",
-            ":label: add or update types",
-            ":seedling: add or update seed files",
-            ":triangular_flag_on_post: add, update, or remove feature flags",
-            ":dizzy: add or update animations an transitions",
-            ":adhesive_bandage: simple fix for a non critical issue",
-            ":monocle_face: data exploration / inspection",
-            ":necktie: add or update business logic",
-            ":stethoscope: add or update healthcheck",
-            ":technologist: improve developer experience",
-            ":thread: add or update code related to multithreading or concurrency",
-            ":safety_vest: add or update code related to validation",
-        ];
-        let repository = MockedRepository::from_commits(commit_messages.clone());
+        let mut changes = documentation_only_changes();
+        changes.other.push(ConventionalCommit {
+            message: ":recycle: refactor the parser".to_string(),
+            hash: "".to_string(),
+            ..Default::default()
+        });
+        let options = ActionOptions {
+            docs_trigger_patch: true,
+            ..Default::default()
+        };
 
         // When
-        let result = Changes::from_repo(&repository).unwrap();
+        let result = changes.define_action_with_options(&options);
 
         // Then
-        let expected_result = Changes {
-            major: Vec::new(),
-            minor: Vec::new(),
-            patch: convert(commit_messages),
-            other: Vec::new(),
+        assert_eq!(result, SemanticVersionAction::Keep);
+    }
+
+    #[test]
+    fn has_documentation_changes_not_triggering_patch() {
+        // Given
+        let changes = documentation_only_changes();
+        let options = ActionOptions {
+            docs_trigger_patch: false,
+            ..Default::default()
         };
-        assert_eq!(result, expected_result);
+
+        // When
+        let result = changes.define_action_with_options(&options);
+
+        // Then
+        assert_eq!(result, SemanticVersionAction::Keep);
     }
 
     #[test]
-    fn creating_from_only_other_conventional_commits() {
-        let commit_messages = vec![
-            ":memo: add or update documentation",
-            ":rocket: deploy stuff",
-            ":tada: begin a project",
-            ":bookmark: release / version tags",
-            ":construction: work in progress",
-            ":pencil2: fix typos",
-            ":poop: write bad code that needs to be improved",
-            ":rewind: revert changes",
-            ":twisted_rightwards_arrows: merge branches",
-            ":page_facing_up: add or update license",
-            ":bulb: add or update comments in source code",
-            "🍻 write code drunkenly",
-            ":bust_in_silhouette: add or update contributor(s)",
-            ":clown_face: mock things",
-            ":see_no_evil: add or update a .gitignore file",
-            ":alembic: perform experiments",
-            ":wastebasket: deprecate code that needs to be cleaned up",
-            ":coffin: remove dead code",
-            ":test_tube: add a failing test",
-            ":bricks: infrastructure related changes",
-            ":money_with_wings: add sponsorship or money related infrastructure",
-        ];
-        let repository = MockedRepository::from_commits(commit_messages.clone());
+    fn has_other_changes_with_overdue_release() {
+        // Given
+        let changes = documentation_only_changes();
+        let options = ActionOptions {
+            release_overdue: true,
+            ..Default::default()
+        };
+
+        // When
+        let result = changes.define_action_with_options(&options);
+
+        // Then
+        assert_eq!(result, SemanticVersionAction::IncrementPatch);
+    }
+
+    #[test]
+    fn breaking_change_during_initial_development() {
+        // Given
+        let changes = Changes::from_messages(vec![":boom: remove the old API".to_string()]);
+        let current_version = Version::new(0, 3, 0);
+        let strict = ActionOptions {
+            current_version: Some(current_version.clone()),
+            zero_ver: ZeroVerMode::Strict,
+            ..Default::default()
+        };
+        let loose = ActionOptions {
+            current_version: Some(current_version.clone()),
+            ..Default::default()
+        };
+
+        // When
+        let strict_action = changes.define_action_with_options(&strict);
+        let loose_action = changes.define_action_with_options(&loose);
+
+        // Then
+        assert_eq!(strict_action, SemanticVersionAction::IncrementMinor);
+        assert_eq!(
+            strict_action.next_version(&current_version),
+            Version::new(0, 4, 0)
+        );
+        assert_eq!(loose_action, SemanticVersionAction::IncrementMajor);
+    }
+
+    #[test]
+    fn feature_during_initial_development() {
+        // Given
+        let changes = Changes::from_messages(vec![":sparkles: add feature".to_string()]);
+        let options = ActionOptions {
+            current_version: Some(Version::new(0, 3, 0)),
+            zero_ver: ZeroVerMode::Strict,
+            ..Default::default()
+        };
+
+        // When
+        let result = changes.define_action_with_options(&options);
+
+        // Then
+        assert_eq!(result, SemanticVersionAction::IncrementPatch);
+    }
+
+    #[test]
+    fn breaking_change_after_initial_development() {
+        // Given
+        let changes = Changes::from_messages(vec![":boom: remove the old API".to_string()]);
+        let options = ActionOptions {
+            current_version: Some(Version::new(1, 3, 0)),
+            zero_ver: ZeroVerMode::Strict,
+            ..Default::default()
+        };
 
         // When
-        let result = Changes::from_repo(&repository).unwrap();
+        let result = changes.define_action_with_options(&options);
 
         // Then
-        let expected_result = Changes {
-            major: Vec::new(),
-            minor: Vec::new(),
-            patch: Vec::new(),
-            other: convert(commit_messages),
-        };
-        assert_eq!(result, expected_result);
+        assert_eq!(result, SemanticVersionAction::IncrementMajor);
     }
 
     #[test]
-    fn creating_from_repo_with_tags() {
+    fn minimum_bump_above_defined_action() {
         // Given
-        let commit_messages = vec![
-            "💥 introduce breaking changes",
-            ":sparkles: introduce new feature",
-            ":money_with_wings: add sponsorship or money related infrastructure",
-            ":memo: add or update documentation",
-        ];
-        let mut repository = MockedRepository::from_commits(commit_messages.clone());
-        repository.latest_version_tag = Some(VersionTag {
-            version: Version::new(1, 0, 0),
-            commit_oid: Oid::zero(),
-        });
-        repository.commit_with_latest_tag = Some(commit_messages[1].into());
+        let changes = documentation_only_changes();
+        let options = ActionOptions {
+            docs_trigger_patch: true,
+            min_bump: Some(SemanticVersionAction::IncrementMinor),
+            ..Default::default()
+        };
 
         // When
-        let result = Changes::from_repo(&repository).unwrap();
+        let result = changes.define_action_with_options(&options);
 
         // Then
-        let expected_result = Changes {
-            major: Vec::new(),
-            minor: Vec::new(),
-            patch: Vec::new(),
-            other: convert(commit_messages[2..].to_vec()),
-        };
-        assert_eq!(result, expected_result);
+        assert_eq!(result, SemanticVersionAction::IncrementMinor);
     }
 
     #[test]
-    fn error_during_fetching_latest_tag() {
+    fn minimum_bump_below_defined_action() {
         // Given
-        let commit_messages = vec![
-            ":sparkles: introduce new feature",
-            ":children_crossing: improve user experience / usability",
-            "💄 add or update the UI and style files",
-            ":iphone: work on responsive design",
-            ":egg: add or update an easter egg",
-            ":chart_with_upwards_trend: add or update analytics or track code",
-            ":heavy_plus_sign: add a dependency",
-            ":heavy_minus_sign: remove a dependency",
-            ":passport_control: work on code related to authorization, roles and permissions",
-        ];
-        let mut repository = MockedRepository::from_commits(commit_messages.clone());
-        repository.tag_fetching_fails = true;
+        let changes = Changes::from_messages(vec![":boom: remove the old API".to_string()]);
+        let options = ActionOptions {
+            min_bump: Some(SemanticVersionAction::IncrementMinor),
+            ..Default::default()
+        };
 
         // When
-        let result = Changes::from_repo(&repository);
+        let result = changes.define_action_with_options(&options);
 
         // Then
-        assert!(result.is_err(), "Expected Error, got Ok");
+        assert_eq!(result, SemanticVersionAction::IncrementMajor);
     }
 
     #[test]
-    fn creating_with_try_from() {
+    fn minimum_bump_without_changes() {
         // Given
-        let commit_messages = vec!["💥 introduce breaking changes"];
-        let (_temp_dir, repository) = repo_init(Some(commit_messages.clone()));
-        let commit = repository
-            .find_commit_by_message("💥 introduce breaking changes")
-            .unwrap();
+        let options = ActionOptions {
+            min_bump: Some(SemanticVersionAction::IncrementPatch),
+            ..Default::default()
+        };
 
         // When
-        let result = Changes::try_from(&repository).unwrap();
+        let result = Changes::default().define_action_with_options(&options);
 
         // Then
-        let expected_result = Changes {
-            major: vec![ConventionalCommit {
-                message: commit.message().unwrap().to_string(),
-                hash: commit.id().to_string(),
-            }],
-            minor: Vec::new(),
-            patch: Vec::new(),
-            other: Vec::new(),
-        };
-        assert_eq!(result, expected_result);
+        assert_eq!(result, SemanticVersionAction::IncrementPatch);
     }
-}
 
-#[cfg(test)]
-mod evaluate_changes_tests {
-    use crate::changes::{Changes, SemanticVersionAction};
-    use crate::repo::ConventionalCommit;
+    #[test]
+    fn has_no_changes_with_overdue_release() {
+        // Given
+        let changes = Changes::default();
+        let options = ActionOptions {
+            release_overdue: true,
+            ..Default::default()
+        };
+
+        // When
+        let result = changes.define_action_with_options(&options);
+
+        // Then
+        assert_eq!(result, SemanticVersionAction::Keep);
+    }
 
     #[test]
     fn has_no_changes() {
@@ -665,7 +3064,9 @@ mod evaluate_changes_tests {
             other: vec![ConventionalCommit {
                 message: "other commit".to_string(),
                 hash: "".to_string(),
+                ..Default::default()
             }],
+            ..Default::default()
         };
 
         // When
@@ -673,6 +3074,7 @@ mod evaluate_changes_tests {
 
         // Then
         assert_eq!(result, SemanticVersionAction::Keep);
+        assert_eq!(changes.counts().other, 1, "changes are not kept");
     }
 
     #[test]
@@ -684,11 +3086,14 @@ mod evaluate_changes_tests {
             patch: vec![ConventionalCommit {
                 message: "patch commit".to_string(),
                 hash: "".to_string(),
+                ..Default::default()
             }],
             other: vec![ConventionalCommit {
                 message: "other commit".to_string(),
                 hash: "".to_string(),
+                ..Default::default()
             }],
+            ..Default::default()
         };
 
         // When
@@ -706,15 +3111,19 @@ mod evaluate_changes_tests {
             minor: vec![ConventionalCommit {
                 message: "minor commit".to_string(),
                 hash: "".to_string(),
+                ..Default::default()
             }],
             patch: vec![ConventionalCommit {
                 message: "patch commit".to_string(),
                 hash: "".to_string(),
+                ..Default::default()
             }],
             other: vec![ConventionalCommit {
                 message: "other commit".to_string(),
                 hash: "".to_string(),
+                ..Default::default()
             }],
+            ..Default::default()
         };
 
         // When
@@ -731,19 +3140,24 @@ mod evaluate_changes_tests {
             major: vec![ConventionalCommit {
                 message: "major commit".to_string(),
                 hash: "".to_string(),
+                ..Default::default()
             }],
             minor: vec![ConventionalCommit {
                 message: "minor commit".to_string(),
                 hash: "".to_string(),
+                ..Default::default()
             }],
             patch: vec![ConventionalCommit {
                 message: "patch commit".to_string(),
                 hash: "".to_string(),
+                ..Default::default()
             }],
             other: vec![ConventionalCommit {
                 message: "other commit".to_string(),
                 hash: "".to_string(),
+                ..Default::default()
             }],
+            ..Default::default()
         };
 
         // When
@@ -752,4 +3166,386 @@ mod evaluate_changes_tests {
         // Then
         assert_eq!(result, SemanticVersionAction::IncrementMajor);
     }
+
+    #[test]
+    fn reverted_feature_keeps_version() {
+        // Given
+        let mut changes = Changes {
+            major: Vec::new(),
+            minor: vec![ConventionalCommit {
+                message: "✨ add feature".to_string(),
+                hash: "3c8e19bf7fe7ba3dd6f0489374a5200fad124377".to_string(),
+                ..Default::default()
+            }],
+            patch: Vec::new(),
+            other: vec![ConventionalCommit {
+                message: "⏪️ Revert \"✨ add feature\"\n\nThis reverts commit 3c8e19bf7fe7ba3dd6f0489374a5200fad124377.\n".to_string(),
+                hash: "8e50d697e09574e94ea0af1136f3ef73ca3519f0".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        // When
+        changes.collapse_reverts();
+        let result = changes.define_action_for_semantic_version();
+
+        // Then
+        assert_eq!(result, SemanticVersionAction::Keep);
+    }
+
+    #[test]
+    fn revert_in_custom_category_is_collapsed() {
+        // Given
+        let mut changes = Changes {
+            major: Vec::new(),
+            minor: vec![ConventionalCommit {
+                message: "✨ add feature".to_string(),
+                hash: "3c8e19bf7fe7ba3dd6f0489374a5200fad124377".to_string(),
+                ..Default::default()
+            }],
+            patch: vec![ConventionalCommit {
+                message: "⏪️ Revert \"✨ add feature\"\n\nThis reverts commit 3c8e19bf7fe7ba3dd6f0489374a5200fad124377.\n".to_string(),
+                hash: "8e50d697e09574e94ea0af1136f3ef73ca3519f0".to_string(),
+                ..Default::default()
+            }],
+            other: Vec::new(),
+            ..Default::default()
+        };
+
+        // When
+        changes.collapse_reverts();
+
+        // Then
+        assert_eq!(changes, Changes::default());
+    }
+
+    #[test]
+    fn revert_of_released_commit_is_kept() {
+        // Given
+        let revert_commit = ConventionalCommit {
+            message: "⏪️ Revert \"✨ add feature\"\n\nThis reverts commit 3c8e19bf7fe7ba3dd6f0489374a5200fad124377.\n".to_string(),
+            hash: "8e50d697e09574e94ea0af1136f3ef73ca3519f0".to_string(),
+            ..Default::default()
+        };
+        let mut changes = Changes {
+            major: Vec::new(),
+            minor: Vec::new(),
+            patch: Vec::new(),
+            other: vec![revert_commit.clone()],
+            ..Default::default()
+        };
+
+        // When
+        changes.collapse_reverts();
+
+        // Then
+        let expected_result = Changes {
+            major: Vec::new(),
+            minor: Vec::new(),
+            patch: Vec::new(),
+            other: vec![revert_commit],
+            ..Default::default()
+        };
+        assert_eq!(changes, expected_result);
+    }
+}
+
+#[cfg(test)]
+mod append_commit_tests {
+    use crate::changes::Changes;
+    use crate::intention::Category;
+    use crate::repo::ConventionalCommit;
+
+    fn commit(message: &str) -> ConventionalCommit {
+        ConventionalCommit {
+            message: message.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn appending_commit_of_each_category() {
+        // Given
+        let mut changes = Changes::default();
+
+        // When
+        changes.append_commit(commit(":boom: remove the old API"));
+        changes.append_commit(commit(":sparkles: add feature"));
+        changes.append_commit(commit(":bug: fix the parser"));
+        changes.append_commit(commit(":memo: update the readme"));
+
+        // Then
+        for (category, message) in [
+            (Category::Major, ":boom: remove the old API"),
+            (Category::Minor, ":sparkles: add feature"),
+            (Category::Patch, ":bug: fix the parser"),
+            (Category::Other, ":memo: update the readme"),
+        ] {
+            assert_eq!(changes.commits(category), [commit(message)]);
+        }
+    }
+
+    #[test]
+    fn appending_commit_with_multiple_intentions() {
+        // Given
+        let mut changes = Changes::default();
+
+        // When
+        changes.append_commit(commit(":bug: fix the parser :sparkles: add feature"));
+
+        // Then
+        assert_eq!(
+            changes.commits(Category::Patch),
+            [commit(":bug: fix the parser :sparkles: add feature")]
+        );
+        assert!(changes.commits(Category::Minor).is_empty());
+        assert_eq!(changes.total_commits_analyzed(), 1);
+    }
+
+    #[test]
+    fn removing_category() {
+        // Given
+        let mut changes = Changes::default();
+        changes.append_commit(commit(":sparkles: add feature"));
+        changes.append_commit(commit(":memo: update the readme"));
+
+        // When
+        changes.remove_category(Category::Other);
+
+        // Then
+        assert!(changes.commits(Category::Other).is_empty());
+        assert_eq!(
+            changes.commits(Category::Minor),
+            [commit(":sparkles: add feature")]
+        );
+        assert_eq!(changes.total_commits_analyzed(), 2);
+    }
+
+    #[test]
+    fn appending_commit_without_intention() {
+        // Given
+        let mut changes = Changes::default();
+
+        // When
+        changes.append_commit(commit("update the readme"));
+
+        // Then
+        assert_eq!(changes, Changes::default());
+    }
+}
+
+#[cfg(test)]
+mod total_commits_analyzed_tests {
+    use crate::changes::Changes;
+    use crate::test_util::repo_init;
+
+    #[test]
+    fn counting_analyzed_commits() {
+        // Given
+        let commit_messages = vec![
+            ":tada: initial commit",
+            ":sparkles: add feature",
+            "plain commit",
+            ":bug: fix bug",
+            "another plain commit",
+        ];
+        let (_temp_dir, repository) = repo_init(Some(commit_messages));
+
+        // When
+        let changes = Changes::from_repo(&repository).unwrap();
+
+        // Then
+        let classified = (&changes).into_iter().count();
+        assert_eq!(changes.total_commits_analyzed(), 5);
+        assert_eq!(classified, 3);
+        assert_eq!(changes.report(None).total_commits_analyzed, 5);
+    }
+
+    #[test]
+    fn collecting_unrecognized_commits() {
+        // Given
+        let commit_messages = vec![":sparkles: add feature", "plain commit"];
+        let (_temp_dir, repository) = repo_init(Some(commit_messages));
+
+        // When
+        let changes = Changes::from_repo(&repository).unwrap();
+
+        // Then
+        let unrecognized: Vec<&str> = changes
+            .unrecognized()
+            .iter()
+            .map(|commit| commit.message())
+            .collect();
+        assert_eq!(unrecognized, vec!["plain commit"]);
+    }
+}
+
+#[cfg(test)]
+mod exclude_marked_tests {
+    use crate::changes::{Changes, SemanticVersionAction};
+
+    #[test]
+    fn excluding_marked_feature() {
+        // Given
+        let mut changes = Changes::from_messages(vec![
+            ":sparkles: add feature\n\n[skip release]".to_string(),
+            ":bug: fix bug".to_string(),
+            "tweak CI [skip release]".to_string(),
+        ]);
+
+        // When
+        changes.exclude_marked("[skip release]");
+
+        // Then
+        assert_eq!(
+            changes.define_action_for_semantic_version(),
+            SemanticVersionAction::IncrementPatch
+        );
+        assert!(changes.unrecognized().is_empty());
+    }
+
+    #[test]
+    fn excluding_nothing_with_empty_marker() {
+        // Given
+        let mut changes = Changes::from_messages(vec![
+            ":sparkles: add feature".to_string(),
+            ":bug: fix bug".to_string(),
+        ]);
+
+        // When
+        changes.exclude_marked("");
+
+        // Then
+        assert_eq!(
+            changes.define_action_for_semantic_version(),
+            SemanticVersionAction::IncrementMinor
+        );
+    }
+}
+
+#[cfg(test)]
+mod parse_action_tests {
+    use crate::changes::SemanticVersionAction;
+
+    #[test]
+    fn parsing_each_action() {
+        for (name, action) in [
+            ("major", SemanticVersionAction::IncrementMajor),
+            ("Minor", SemanticVersionAction::IncrementMinor),
+            ("PATCH", SemanticVersionAction::IncrementPatch),
+            ("keep", SemanticVersionAction::Keep),
+        ] {
+            assert_eq!(name.parse::<SemanticVersionAction>(), Ok(action));
+        }
+    }
+
+    #[test]
+    fn parsing_unknown_action() {
+        // When
+        let result = "minimal".parse::<SemanticVersionAction>();
+
+        // Then
+        let error = result.expect_err("Expected an error, but got Ok");
+        assert!(
+            error.contains("minimal"),
+            "error message doesn't name the input: {error}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod history_tests {
+    use crate::changes::Changes;
+    use crate::intention::{Category, IntentionMap};
+    use crate::repo::{FetchOptions, VersionScheme};
+    use crate::test_util::{repo_init, RepositoryTestExtensions};
+    use semver::Version;
+
+    #[test]
+    fn splitting_history_by_version_tags() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+        repository.add_tag(
+            repository
+                .find_commit_by_message(":tada: initial commit")
+                .unwrap(),
+            "v1.0.0",
+        );
+        repository.add_commit(":sparkles: add feature");
+        repository.add_tag(
+            repository
+                .find_commit_by_message(":sparkles: add feature")
+                .unwrap(),
+            "v1.1.0",
+        );
+        repository.add_commit(":bug: fix bug");
+
+        // When
+        let result = Changes::history(
+            &repository,
+            &FetchOptions::default(),
+            &IntentionMap::default(),
+        )
+        .unwrap();
+
+        // Then
+        let sections: Vec<_> = result
+            .iter()
+            .map(|(version_tag, changes)| {
+                (
+                    version_tag.as_ref().map(|tag| tag.version.clone()),
+                    changes.counts(),
+                )
+            })
+            .collect();
+        assert_eq!(sections.len(), 3, "{sections:?}");
+        assert_eq!(sections[0].0, None);
+        assert_eq!(sections[1].0, Some(Version::new(1, 1, 0)));
+        assert_eq!(sections[2].0, Some(Version::new(1, 0, 0)));
+        assert_eq!(result[0].1.commits(Category::Patch).len(), 1);
+        assert_eq!(result[1].1.commits(Category::Minor).len(), 1);
+        assert_eq!(result[2].1.commits(Category::Other).len(), 1);
+    }
+
+    #[test]
+    fn splitting_history_by_calendar_version_tags() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+        repository.add_tag(
+            repository
+                .find_commit_by_message(":tada: initial commit")
+                .unwrap(),
+            "v2024.05.0",
+        );
+        repository.add_commit(":sparkles: add feature");
+        repository.add_tag(
+            repository
+                .find_commit_by_message(":sparkles: add feature")
+                .unwrap(),
+            "v2024.06.0",
+        );
+        let options = FetchOptions {
+            scheme: VersionScheme::CalVer,
+            ..Default::default()
+        };
+
+        // When
+        let result = Changes::history(&repository, &options, &IntentionMap::default()).unwrap();
+
+        // Then
+        let versions: Vec<_> = result
+            .iter()
+            .map(|(version_tag, _)| version_tag.as_ref().map(|tag| tag.version.clone()))
+            .collect();
+        assert_eq!(
+            versions,
+            [
+                None,
+                Some(Version::new(2024, 6, 0)),
+                Some(Version::new(2024, 5, 0))
+            ]
+        );
+        assert_eq!(result[1].1.commits(Category::Minor).len(), 1);
+    }
 }
@@ -0,0 +1,158 @@
+use crate::intention::{Category, IntentionMap};
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use toml_edit::DocumentMut;
+
+/// Name of the configuration file that is read from the root of the repository by default.
+pub const CONFIG_FILE_NAME: &str = ".semantic-release.toml";
+
+/// Structure that represents the configuration file of the tool.
+///
+/// The configuration registers custom intentions:
+///
+/// ```toml
+/// [[intentions]]
+/// shortcode = ":sparkling_heart:"
+/// emoji = "💖"
+/// category = "minor"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    /// Shortcode, emoji and category of the custom intentions
+    pub intentions: Vec<(String, String, Category)>,
+}
+
+impl Config {
+    /// Load the configuration from a file.
+    ///
+    /// ## Returns
+    ///
+    /// The [`Config`], or an error if the file is missing or invalid.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let content = fs::read_to_string(path)
+            .map_err(|error| format!("can't read config file {}: {error}", path.display()))?;
+        Self::parse(&content)
+            .map_err(|error| format!("invalid config file {}: {error}", path.display()).into())
+    }
+
+    /// Parse the configuration from the content of a configuration file.
+    ///
+    /// ## Returns
+    ///
+    /// The [`Config`], or an error if the content is invalid.
+    pub fn parse(content: &str) -> Result<Self, Box<dyn Error>> {
+        let document: DocumentMut = content.parse()?;
+        let intentions = match document.get("intentions") {
+            Some(item) => item
+                .as_array_of_tables()
+                .ok_or("`intentions` must be an array of tables")?
+                .iter()
+                .enumerate()
+                .map(|(index, table)| {
+                    let field = |key: &str| {
+                        table
+                            .get(key)
+                            .and_then(|value| value.as_str())
+                            .ok_or_else(|| format!("intention #{} without `{key}`", index + 1))
+                    };
+                    // An empty shortcode or emoji would be found in every commit message.
+                    let pattern = |key: &str| match field(key)? {
+                        "" => Err(format!("intention #{} has an empty `{key}`", index + 1)),
+                        pattern => Ok(pattern.to_string()),
+                    };
+                    Ok((
+                        pattern("shortcode")?,
+                        pattern("emoji")?,
+                        field("category")?.parse()?,
+                    ))
+                })
+                .collect::<Result<_, Box<dyn Error>>>()?,
+            None => Vec::new(),
+        };
+        Ok(Self { intentions })
+    }
+
+    /// Register the custom intentions of the configuration into an [`IntentionMap`].
+    ///
+    /// ## Returns
+    ///
+    /// An error if an intention can't be registered, see [`IntentionMap::register`].
+    pub fn apply(&self, intention_map: &mut IntentionMap) -> Result<(), Box<dyn Error>> {
+        for (shortcode, emoji, category) in &self.intentions {
+            intention_map.register(shortcode, emoji, *category)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod config_tests {
+    use crate::config::Config;
+    use crate::intention::{Category, IntentionMap};
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn loading_config_with_custom_intention() {
+        // Given
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("release.toml");
+        fs::write(
+            &config_path,
+            "[[intentions]]\nshortcode = \":sparkling_heart:\"\nemoji = \"💖\"\ncategory = \"minor\"\n",
+        )
+        .unwrap();
+
+        // When
+        let config = Config::load(&config_path).unwrap();
+        let mut intention_map = IntentionMap::default();
+        config.apply(&mut intention_map).unwrap();
+
+        // Then
+        assert_eq!(
+            intention_map.category_of("💖 add sponsors"),
+            Some(Category::Minor)
+        );
+    }
+
+    #[test]
+    fn loading_missing_config() {
+        // Given
+        let temp_dir = TempDir::new().unwrap();
+
+        // When
+        let result = Config::load(&temp_dir.path().join("missing.toml"));
+
+        // Then
+        assert!(result.is_err(), "Expected an error, but got {result:?}");
+    }
+
+    #[test]
+    fn parsing_intention_with_empty_shortcode() {
+        // When
+        let result = Config::parse(
+            "[[intentions]]\nshortcode = \":sparkling_heart:\"\nemoji = \"💖\"\ncategory = \"minor\"\n\n[[intentions]]\nshortcode = \"\"\nemoji = \"🩹\"\ncategory = \"major\"\n",
+        );
+
+        // Then
+        let error = result.expect_err("Expected an error, but got Ok");
+        assert!(
+            error
+                .to_string()
+                .contains("intention #2 has an empty `shortcode`"),
+            "error message doesn't name the bad entry: {error}"
+        );
+    }
+
+    #[test]
+    fn parsing_intention_with_unknown_category() {
+        // When
+        let result = Config::parse(
+            "[[intentions]]\nshortcode = \":sparkling_heart:\"\nemoji = \"💖\"\ncategory = \"huge\"\n",
+        );
+
+        // Then
+        assert!(result.is_err(), "Expected an error, but got {result:?}");
+    }
+}
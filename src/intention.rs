@@ -0,0 +1,939 @@
+#[cfg(feature = "git")]
+use crate::repo::ConventionalCommit;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Shortcode and emoji of a gitmoji, e.g. `(":boom:", "💥")`
+pub type Gitmoji = (&'static str, &'static str);
+
+/// Intentions of the commits, grouped by the category of the changes they introduce.
+///
+/// This table is the single source of truth of the built-in intentions.
+const INTENTIONS: [(Category, &[Gitmoji]); 4] = [
+    (Category::Major, &[(":boom:", "💥")]),
+    (
+        Category::Minor,
+        &[
+            (":sparkles:", "✨"),
+            (":children_crossing:", "🚸"),
+            (":lipstick:", "💄"),
+            (":iphone:", "📱"),
+            (":egg:", "🥚"),
+            (":chart_with_upwards_trend:", "📈"),
+            (":heavy_plus_sign:", "➕"),
+            (":heavy_minus_sign:", "➖"),
+            (":passport_control:", "🛂"),
+        ],
+    ),
+    (
+        Category::Patch,
+        &[
+            (":art:", "🎨"),
+            (":ambulance:", "🚑️"),
+            (":lock:", "🔒️"),
+            (":bug:", "🐛"),
+            (":zap:", "⚡️"),
+            (":goal_net:", "🥅"),
+            (":alien:", "👽️"),
+            (":wheelchair:", "♿️"),
+            (":speech_balloon:", "💬"),
+            (":mag:", "🔍️"),
+            (":fire:", "🔥"),
+            (":white_check_mark:", "✅"),
+            (":closed_lock_with_key:", "🔐"),
+            (":rotating_light:", "🚨"),
+            (":green_heart:", "💚"),
+            (":arrow_down:", "⬇️"),
+            (":arrow_up:", "⬆️"),
+            (":pushpin:", "📌"),
+            (":construction_worker:", "👷"),
+            (":recycle:", "♻️"),
+            (":wrench:", "🔧"),
+            (":hammer:", "🔨"),
+            (":globe_with_meridians:", "🌐"),
+            (":package:", "📦️"),
+            (":truck:", "🚚"),
+            (":bento:", "🍱"),
+            (":card_file_box:", "🗃️"),
+            (":loud_sound:", "🔊"),
+            (":mute:", "🔇"),
+            (":building_construction:", "🏗️"),
+            (":camera_flash:", "📸"),
+            (":label:", "🏷️"),
+            (":seedling:", "🌱"),
+            (":triangular_flag_on_post:", "🚩"),
+            (":dizzy:", "💫"),
+            (":adhesive_bandage:", "🩹"),
+            (":monocle_face:", "🧐"),
+            (":necktie:", "👔"),
+            (":stethoscope:", "🩺"),
+            (":technologist:", "🧑‍💻"),
+            (":thread:", "🧵"),
+            (":safety_vest:", "🦺"),
+        ],
+    ),
+    (
+        Category::Other,
+        &[
+            (":memo:", "📝"),
+            (":rocket:", "🚀"),
+            (":tada:", "🎉"),
+            (":bookmark:", "🔖"),
+            (":construction:", "🚧"),
+            (":pencil2:", "✏️"),
+            (":poop:", "💩"),
+            (":rewind:", "⏪️"),
+            (":twisted_rightwards_arrows:", "🔀"),
+            (":page_facing_up:", "📄"),
+            (":bulb:", "💡"),
+            (":beers:", "🍻"),
+            (":bust_in_silhouette:", "👥"),
+            (":clown_face:", "🤡"),
+            (":see_no_evil:", "🙈"),
+            (":alembic:", "⚗️"),
+            (":wastebasket:", "🗑️"),
+            (":coffin:", "⚰️"),
+            (":test_tube:", "🧪"),
+            (":bricks:", "🧱"),
+            (":money_with_wings:", "💸"),
+        ],
+    ),
+];
+
+/// Shortcodes of the intentions that only change the documentation
+#[cfg(feature = "git")]
+const DOCUMENTATION_SHORTCODES: [&str; 2] = [":memo:", ":pencil2:"];
+
+/// Return the built-in gitmojis that only change the documentation, looked up in the intention
+/// table so they cannot drift from their category.
+#[cfg(feature = "git")]
+pub(crate) fn documentation_gitmojis() -> impl Iterator<Item = &'static Gitmoji> {
+    Category::Other
+        .gitmojis()
+        .iter()
+        .filter(|(shortcode, _)| DOCUMENTATION_SHORTCODES.contains(shortcode))
+}
+
+/// Enum to represent the change categories of the commits
+///
+/// Categories are ordered from the highest to the lowest: `Major < Minor < Patch < Other`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Category {
+    Major,
+    Minor,
+    Patch,
+    Other,
+}
+
+impl Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Category::Major => write!(f, "major"),
+            Category::Minor => write!(f, "minor"),
+            Category::Patch => write!(f, "patch"),
+            Category::Other => write!(f, "other"),
+        }
+    }
+}
+
+impl Category {
+    /// Return the built-in gitmojis of the category, e.g. for suggesting the gitmoji of a
+    /// commit message.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::Category;
+    ///
+    /// assert!(Category::Major.gitmojis().contains(&(":boom:", "💥")));
+    /// ```
+    pub fn gitmojis(&self) -> &'static [Gitmoji] {
+        INTENTIONS
+            .iter()
+            .find(|(category, _)| category == self)
+            .map_or(&[], |(_, gitmojis)| gitmojis)
+    }
+}
+
+impl FromStr for Category {
+    type Err = String;
+
+    /// Parse a category from its name, like it's displayed, e.g. `minor`.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "major" => Ok(Category::Major),
+            "minor" => Ok(Category::Minor),
+            "patch" => Ok(Category::Patch),
+            "other" => Ok(Category::Other),
+            _ => Err(format!(
+                "unknown category `{name}`, expected major, minor, patch or other"
+            )),
+        }
+    }
+}
+
+/// Structure that maps the commit intentions to change categories
+#[derive(Debug, Clone)]
+pub struct IntentionMap {
+    /// Shortcode, emoji and category of each intention
+    intentions: Vec<(String, String, Category)>,
+    /// Choose the highest category instead of the first intention for commits with
+    /// multiple intentions
+    highest_wins: bool,
+    /// Classify each line of the message on its own and choose the highest category
+    /// of the lines
+    scan_body: bool,
+    /// Classify the commits without a known intention as other changes instead of
+    /// dropping them
+    keep_unrecognized: bool,
+}
+
+impl Default for IntentionMap {
+    /// Create the [`IntentionMap`] of the built-in gitmoji intentions.
+    fn default() -> Self {
+        let intentions = INTENTIONS
+            .into_iter()
+            .flat_map(|(category, intentions)| {
+                intentions.iter().map(move |(shortcode, emoji)| {
+                    (shortcode.to_string(), emoji.to_string(), category)
+                })
+            })
+            .collect();
+        Self {
+            intentions,
+            highest_wins: false,
+            scan_body: false,
+            keep_unrecognized: false,
+        }
+    }
+}
+
+/// Enum to represent how the intentions of the commit messages are rendered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmojiStyle {
+    /// Unicode emoji, e.g. `✨`
+    Unicode,
+    /// Shortcode of the emoji, e.g. `:sparkles:`
+    Shortcode,
+}
+
+impl IntentionMap {
+    /// Register a custom intention of the given category.
+    ///
+    /// Custom intentions take precedence over the built-in ones: an intention with the
+    /// same shortcode or emoji as an already known intention replaces it.
+    ///
+    /// ## Returns
+    ///
+    /// An error if the shortcode or the emoji is empty, because an empty intention would
+    /// be found in every message.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::{Category, IntentionMap};
+    ///
+    /// let mut intention_map = IntentionMap::default();
+    /// intention_map
+    ///     .register(":sparkling_heart:", "💖", Category::Minor)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     intention_map.category_of(":sparkling_heart: add sponsors"),
+    ///     Some(Category::Minor)
+    /// );
+    /// ```
+    pub fn register(
+        &mut self,
+        shortcode: &str,
+        emoji: &str,
+        category: Category,
+    ) -> Result<(), Box<dyn Error>> {
+        if shortcode.is_empty() || emoji.is_empty() {
+            return Err("the shortcode and the emoji of an intention can't be empty".into());
+        }
+        self.intentions.retain(|(known_shortcode, known_emoji, _)| {
+            known_shortcode != shortcode && known_emoji != emoji
+        });
+        self.intentions
+            .insert(0, (shortcode.to_string(), emoji.to_string(), category));
+        Ok(())
+    }
+
+    /// Choose how commits with multiple intentions are classified.
+    ///
+    /// By default the intention appearing first decides the category. When `highest_wins`
+    /// is `true`, the highest category of the intentions is chosen instead, where
+    /// major > minor > patch > other.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::{Category, IntentionMap};
+    ///
+    /// let mut intention_map = IntentionMap::default();
+    /// intention_map.set_highest_wins(true);
+    ///
+    /// assert_eq!(
+    ///     intention_map.category_of(":sparkles: add feature\n\n:boom: remove the old API"),
+    ///     Some(Category::Major)
+    /// );
+    /// ```
+    pub fn set_highest_wins(&mut self, highest_wins: bool) {
+        self.highest_wins = highest_wins;
+    }
+
+    /// Choose whether the lines of the commit body are classified on their own.
+    ///
+    /// Squash merges pack the messages of multiple commits into the body of a single
+    /// commit. When `scan_body` is `true`, every line of the message is classified on its
+    /// own and the highest category of the lines is chosen.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::{Category, IntentionMap};
+    ///
+    /// let mut intention_map = IntentionMap::default();
+    /// intention_map.set_scan_body(true);
+    ///
+    /// let message = "Merge feature (#12)\n\n* :sparkles: add feature\n* :boom: remove the old API";
+    /// assert_eq!(intention_map.category_of(message), Some(Category::Major));
+    /// ```
+    pub fn set_scan_body(&mut self, scan_body: bool) {
+        self.scan_body = scan_body;
+    }
+
+    /// Choose whether the commits without a known intention are kept as other changes.
+    ///
+    /// By default these commits are dropped, so they don't appear in the changes at all.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::{Category, IntentionMap};
+    ///
+    /// let mut intention_map = IntentionMap::default();
+    /// intention_map.set_keep_unrecognized(true);
+    ///
+    /// assert_eq!(intention_map.category_of("update the readme"), Some(Category::Other));
+    /// ```
+    pub fn set_keep_unrecognized(&mut self, keep_unrecognized: bool) {
+        self.keep_unrecognized = keep_unrecognized;
+    }
+
+    /// Iterate over the known intentions in the order they are matched.
+    ///
+    /// ## Returns
+    ///
+    /// The shortcode, emoji and category of each intention.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::IntentionMap;
+    ///
+    /// for (shortcode, emoji, category) in IntentionMap::default().iter() {
+    ///     println!("{shortcode} {emoji} {category}");
+    /// }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str, Category)> {
+        self.intentions
+            .iter()
+            .map(|(shortcode, emoji, category)| (shortcode.as_str(), emoji.as_str(), *category))
+    }
+
+    /// Render the known intentions of a text in the given style, e.g. for terminals that
+    /// can't show unicode emoji.
+    ///
+    /// ## Returns
+    ///
+    /// The text with each known intention replaced by its emoji or its shortcode.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::{EmojiStyle, IntentionMap};
+    ///
+    /// let rendered = IntentionMap::default().render("✨ add feature", EmojiStyle::Shortcode);
+    /// assert_eq!(rendered, ":sparkles: add feature");
+    /// ```
+    pub fn render(&self, text: &str, style: EmojiStyle) -> String {
+        let mut replacements: Vec<(&str, &str)> = self
+            .iter()
+            .map(|(shortcode, emoji, _)| match style {
+                EmojiStyle::Unicode => (shortcode, emoji),
+                EmojiStyle::Shortcode => (emoji, shortcode),
+            })
+            .collect();
+        // Longer intentions go first, so an emoji isn't replaced by another one that is
+        // its prefix, e.g. without its variation selector.
+        replacements.sort_by_key(|(from, _)| std::cmp::Reverse(from.len()));
+        replacements
+            .into_iter()
+            .fold(text.to_string(), |text, (from, to)| text.replace(from, to))
+    }
+
+    /// Serialize the intentions in the format of the `gitmojis.json` file of the gitmoji
+    /// project, so other gitmoji tools can use the same mapping.
+    ///
+    /// Each entry has the `emoji`, the `code` and the `name` of the gitmoji, the `semver`
+    /// level it releases, which is `null` for other changes, and its `category`.
+    ///
+    /// ## Returns
+    ///
+    /// The pretty printed JSON document.
+    #[cfg(feature = "serde")]
+    pub fn to_gitmojis_json(&self) -> Result<String, serde_json::Error> {
+        let gitmojis: Vec<serde_json::Value> = self
+            .iter()
+            .map(|(shortcode, emoji, category)| {
+                let semver = (category != Category::Other).then(|| category.to_string());
+                serde_json::json!({
+                    "emoji": emoji,
+                    "code": shortcode,
+                    "name": shortcode.trim_matches(':'),
+                    "semver": semver,
+                    "category": category.to_string(),
+                })
+            })
+            .collect();
+        serde_json::to_string_pretty(&serde_json::json!({ "gitmojis": gitmojis }))
+    }
+
+    /// Find the category of a commit message.
+    ///
+    /// When the message has multiple intentions, the one appearing first decides the
+    /// category, unless it's configured otherwise with [`IntentionMap::set_highest_wins`]
+    /// or [`IntentionMap::set_scan_body`].
+    ///
+    /// Intentions are found anywhere in the message, so they may be surrounded by other
+    /// text, e.g. written as `[:bug:] fix the parser` or `(✨) add feature`.
+    ///
+    /// ## Returns
+    ///
+    /// The [`Category`] of the message or `None` if it has no known intention, unless
+    /// [`IntentionMap::set_keep_unrecognized`] is enabled.
+    pub fn category_of(&self, message: &str) -> Option<Category> {
+        let category = if self.scan_body {
+            message
+                .lines()
+                .filter_map(|line| self.category_of_text(line))
+                .min()
+        } else {
+            self.category_of_text(message)
+        };
+        category.or(self.keep_unrecognized.then_some(Category::Other))
+    }
+
+    /// Find the category of a text according to its intentions.
+    fn category_of_text(&self, message: &str) -> Option<Category> {
+        let found_intentions = self
+            .intentions
+            .iter()
+            .filter_map(|(shortcode, emoji, category)| {
+                let position = [shortcode, emoji]
+                    .into_iter()
+                    .filter_map(|intention| message.find(intention.as_str()))
+                    .min()?;
+                Some((position, *category))
+            });
+        if self.highest_wins {
+            found_intentions.map(|(_, category)| category).min()
+        } else {
+            found_intentions
+                .min_by_key(|(position, _)| *position)
+                .map(|(_, category)| category)
+        }
+    }
+}
+
+/// Trait to classify commits into change categories
+pub trait Classify {
+    /// Classify the commit according to the intentions of the given [`IntentionMap`].
+    ///
+    /// ## Returns
+    ///
+    /// The [`Category`] of the commit or `None` if it has no known intention.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::{Category, Classify, IntentionMap};
+    /// use git2::Repository;
+    ///
+    /// let git_repo = Repository::open(".").unwrap();
+    /// let head = git_repo.head().unwrap().peel_to_commit().unwrap();
+    ///
+    /// let category = head.category(&IntentionMap::default());
+    /// println!("category of HEAD: {category:?}");
+    /// ```
+    fn category(&self, intention_map: &IntentionMap) -> Option<Category>;
+}
+
+#[cfg(feature = "git")]
+impl Classify for ConventionalCommit {
+    fn category(&self, intention_map: &IntentionMap) -> Option<Category> {
+        intention_map.category_of(&self.message)
+    }
+}
+
+#[cfg(feature = "git")]
+impl Classify for git2::Commit<'_> {
+    fn category(&self, intention_map: &IntentionMap) -> Option<Category> {
+        intention_map.category_of(&String::from_utf8_lossy(self.message_bytes()))
+    }
+}
+
+/// Find the category of a commit message according to the built-in intentions.
+///
+/// This doesn't need the `git` feature, so it can be used to classify messages without
+/// depending on `git2`.
+///
+/// ## Returns
+///
+/// The [`Category`] of the message or `None` if it has no known intention.
+///
+/// ## Example
+///
+/// ```
+/// use cargo_semantic_release::{parse_intention, Category};
+///
+/// assert_eq!(parse_intention("♻️ simplify the parser"), Some(Category::Patch));
+/// ```
+pub fn parse_intention(message: &str) -> Option<Category> {
+    IntentionMap::default().category_of(message)
+}
+
+/// Find the built-in gitmoji that appears first in a commit message.
+#[cfg(feature = "git")]
+pub(crate) fn find_gitmoji(message: &str) -> Option<Gitmoji> {
+    INTENTIONS
+        .iter()
+        .flat_map(|(_, gitmojis)| gitmojis.iter())
+        .filter_map(|gitmoji @ (shortcode, emoji)| {
+            let position = [shortcode, emoji]
+                .into_iter()
+                .filter_map(|intention| message.find(intention))
+                .min()?;
+            Some((position, *gitmoji))
+        })
+        .min_by_key(|(position, _)| *position)
+        .map(|(_, gitmoji)| gitmoji)
+}
+
+/// Classify a [`git2::Commit`] according to the built-in intentions, e.g. while walking a
+/// repository with [`git2::Revwalk`].
+///
+/// ## Returns
+///
+/// The converted commit with its [`Category`], or `None` if the commit has no known
+/// intention.
+///
+/// ## Example
+///
+/// ```
+/// use cargo_semantic_release::classify_git2_commit;
+/// use git2::Repository;
+///
+/// let git_repo = Repository::open(".").unwrap();
+/// let mut revwalk = git_repo.revwalk().unwrap();
+/// revwalk.push_head().unwrap();
+///
+/// for oid in revwalk {
+///     let commit = git_repo.find_commit(oid.unwrap()).unwrap();
+///     if let Some((commit, category)) = classify_git2_commit(&commit) {
+///         println!("{category}: {commit}");
+///     }
+/// }
+/// ```
+#[cfg(feature = "git")]
+pub fn classify_git2_commit(commit: &git2::Commit) -> Option<(ConventionalCommit, Category)> {
+    let commit = ConventionalCommit::from_git2_commit(commit.clone());
+    let category = commit.category(&IntentionMap::default())?;
+    Some((commit, category))
+}
+
+#[cfg(all(test, feature = "git"))]
+mod intention_tests {
+    use crate::intention::{
+        classify_git2_commit, documentation_gitmojis, parse_intention, Category, Classify,
+        IntentionMap, DOCUMENTATION_SHORTCODES, INTENTIONS,
+    };
+    use crate::repo::ConventionalCommit;
+    use crate::test_util::{repo_init, RepositoryTestExtensions};
+
+    fn classify(message: &str) -> Option<Category> {
+        ConventionalCommit {
+            message: message.to_string(),
+            ..Default::default()
+        }
+        .category(&IntentionMap::default())
+    }
+
+    #[test]
+    fn every_gitmoji_is_in_exactly_one_category() {
+        // Given
+        let gitmojis = INTENTIONS.iter().flat_map(|(category, gitmojis)| {
+            gitmojis.iter().map(move |gitmoji| (gitmoji, category))
+        });
+
+        // When
+        let mut categories_by_shortcode = std::collections::HashMap::new();
+        let mut categories_by_emoji = std::collections::HashMap::new();
+        for ((shortcode, emoji), category) in gitmojis {
+            categories_by_shortcode
+                .entry(*shortcode)
+                .or_insert_with(Vec::new)
+                .push(*category);
+            categories_by_emoji
+                .entry(*emoji)
+                .or_insert_with(Vec::new)
+                .push(*category);
+        }
+
+        // Then
+        for (shortcode, categories) in categories_by_shortcode {
+            assert_eq!(categories.len(), 1, "{shortcode} is in {categories:?}");
+            assert_eq!(classify(shortcode), Some(categories[0]));
+            assert_eq!(
+                parse_intention(&format!("{shortcode} change")),
+                Some(categories[0]),
+                "{shortcode}"
+            );
+        }
+        for (emoji, categories) in categories_by_emoji {
+            assert_eq!(categories.len(), 1, "{emoji} is in {categories:?}");
+            assert_eq!(
+                parse_intention(&format!("{emoji} change")),
+                Some(categories[0]),
+                "{emoji}"
+            );
+        }
+    }
+
+    #[test]
+    fn documentation_gitmojis_are_from_the_intention_table() {
+        // When
+        let shortcodes: Vec<&str> = documentation_gitmojis()
+            .map(|(shortcode, _)| *shortcode)
+            .collect();
+
+        // Then
+        assert_eq!(shortcodes, DOCUMENTATION_SHORTCODES);
+    }
+
+    #[test]
+    fn classifying_major_commit() {
+        assert_eq!(classify(":boom: remove the old API"), Some(Category::Major));
+    }
+
+    #[test]
+    fn classifying_minor_commit() {
+        assert_eq!(classify("✨ add new feature"), Some(Category::Minor));
+    }
+
+    #[test]
+    fn classifying_patch_commit() {
+        assert_eq!(classify(":bug: fix the parser"), Some(Category::Patch));
+    }
+
+    #[test]
+    fn classifying_other_commit() {
+        assert_eq!(classify("📝 update the readme"), Some(Category::Other));
+    }
+
+    #[test]
+    fn classifying_commit_without_intention() {
+        assert_eq!(classify("update the readme"), None);
+    }
+
+    #[test]
+    fn classifying_commit_with_custom_intention() {
+        // Given
+        let mut intention_map = IntentionMap::default();
+        intention_map
+            .register(":sparkling_heart:", "💖", Category::Minor)
+            .unwrap();
+        let commit = ConventionalCommit {
+            message: "💖 add sponsors".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.category(&intention_map);
+
+        // Then
+        assert_eq!(result, Some(Category::Minor));
+    }
+
+    #[test]
+    fn custom_intention_overrides_built_in_intention() {
+        // Given
+        let mut intention_map = IntentionMap::default();
+        intention_map
+            .register(":memo:", "📝", Category::Patch)
+            .unwrap();
+        let commit = ConventionalCommit {
+            message: ":memo: update the readme".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.category(&intention_map);
+
+        // Then
+        assert_eq!(result, Some(Category::Patch));
+    }
+
+    #[test]
+    fn first_intention_decides_the_category() {
+        assert_eq!(
+            classify(":memo: document the :boom: changes"),
+            Some(Category::Other)
+        );
+    }
+
+    #[test]
+    fn highest_intention_decides_the_category_when_configured() {
+        // Given
+        let mut intention_map = IntentionMap::default();
+        intention_map.set_highest_wins(true);
+        let commit = ConventionalCommit {
+            message: ":sparkles: add feature\n\n:boom: remove the old API".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.category(&intention_map);
+
+        // Then
+        assert_eq!(result, Some(Category::Major));
+    }
+
+    #[test]
+    fn first_intention_decides_the_category_by_default() {
+        // Given
+        let commit = ConventionalCommit {
+            message: ":sparkles: add feature\n\n:boom: remove the old API".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.category(&IntentionMap::default());
+
+        // Then
+        assert_eq!(result, Some(Category::Minor));
+    }
+
+    #[test]
+    fn scanning_body_of_squash_commit() {
+        // Given
+        let mut intention_map = IntentionMap::default();
+        intention_map.set_scan_body(true);
+        let commit = ConventionalCommit {
+            message: "Merge feature (#12)\n\n* :sparkles: add feature\n* :bug: fix the parser\n* 💥 remove the old API\n".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.category(&intention_map);
+
+        // Then
+        assert_eq!(result, Some(Category::Major));
+    }
+
+    #[test]
+    fn first_intention_of_each_line_decides_when_scanning_body() {
+        // Given
+        let mut intention_map = IntentionMap::default();
+        intention_map.set_scan_body(true);
+        let commit = ConventionalCommit {
+            message: "Merge docs (#13)\n\n* :memo: document the :boom: changes\n* :bug: fix typo"
+                .to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.category(&intention_map);
+
+        // Then
+        assert_eq!(result, Some(Category::Patch));
+    }
+
+    #[test]
+    fn classifying_recognized_git2_commit() {
+        // Given
+        let commit_message = ":sparkles: add feature";
+        let (_temp_dir, repository) = repo_init(Some(vec![commit_message]));
+        let commit = repository.find_commit_by_message(commit_message).unwrap();
+
+        // When
+        let (result, category) = classify_git2_commit(&commit).unwrap();
+
+        // Then
+        assert_eq!(result.message(), commit_message);
+        assert_eq!(result.hash, commit.id().to_string());
+        assert_eq!(category, Category::Minor);
+    }
+
+    #[test]
+    fn classifying_unrecognized_git2_commit() {
+        // Given
+        let commit_message = "plain commit";
+        let (_temp_dir, repository) = repo_init(Some(vec![commit_message]));
+        let commit = repository.find_commit_by_message(commit_message).unwrap();
+
+        // When
+        let result = classify_git2_commit(&commit);
+
+        // Then
+        assert!(result.is_none(), "Expected None, but got {result:?}");
+    }
+}
+
+/// Tests of the API that is available without the `git` feature
+#[cfg(test)]
+mod parse_intention_tests {
+    use crate::intention::{parse_intention, Category, EmojiStyle, IntentionMap};
+
+    /// Every category, kept in sync with [`Category`] by the exhaustive `match`, so that a new
+    /// category fails to compile here until it is listed.
+    fn all_categories() -> [Category; 4] {
+        let categories = [
+            Category::Major,
+            Category::Minor,
+            Category::Patch,
+            Category::Other,
+        ];
+        for category in categories {
+            match category {
+                Category::Major | Category::Minor | Category::Patch | Category::Other => {}
+            }
+        }
+        categories
+    }
+
+    #[test]
+    fn registering_empty_intention() {
+        // Given
+        let mut intention_map = IntentionMap::default();
+
+        // When
+        let empty_shortcode = intention_map.register("", "💖", Category::Major);
+        let empty_emoji = intention_map.register(":sparkling_heart:", "", Category::Major);
+
+        // Then
+        assert!(empty_shortcode.is_err(), "Expected an error, but got Ok");
+        assert!(empty_emoji.is_err(), "Expected an error, but got Ok");
+        assert_eq!(intention_map.category_of("update the readme"), None);
+    }
+
+    #[test]
+    fn every_category_has_gitmojis() {
+        for category in all_categories() {
+            assert!(
+                !category.gitmojis().is_empty(),
+                "{category} has no gitmojis"
+            );
+        }
+    }
+
+    #[test]
+    fn rendering_intentions_in_each_style() {
+        // Given
+        let intention_map = IntentionMap::default();
+
+        // When
+        let shortcodes = intention_map.render("✨ add feature, 🐛 fix bug", EmojiStyle::Shortcode);
+        let emojis = intention_map.render(":sparkles: add feature", EmojiStyle::Unicode);
+
+        // Then
+        assert_eq!(shortcodes, ":sparkles: add feature, :bug: fix bug");
+        assert_eq!(emojis, "✨ add feature");
+    }
+
+    #[test]
+    fn parsing_intention_of_each_category() {
+        assert_eq!(
+            parse_intention("💥 remove the old API"),
+            Some(Category::Major)
+        );
+        assert_eq!(
+            parse_intention(":sparkles: add feature"),
+            Some(Category::Minor)
+        );
+        assert_eq!(parse_intention(":recycle: refactor"), Some(Category::Patch));
+        assert_eq!(parse_intention(":memo: update docs"), Some(Category::Other));
+        assert_eq!(parse_intention("update docs"), None);
+    }
+
+    #[test]
+    fn listing_built_in_intentions() {
+        // Given
+        let intention_map = IntentionMap::default();
+
+        // When
+        let intentions: Vec<_> = intention_map.iter().collect();
+
+        // Then
+        assert!(intentions.contains(&(":boom:", "💥", Category::Major)));
+        assert!(intentions.contains(&(":sparkles:", "✨", Category::Minor)));
+        assert!(intentions.contains(&(":bug:", "🐛", Category::Patch)));
+        assert!(intentions.contains(&(":memo:", "📝", Category::Other)));
+    }
+
+    #[test]
+    fn parsing_intention_surrounded_by_brackets() {
+        assert_eq!(
+            parse_intention("[:bug:] fix the parser"),
+            Some(Category::Patch)
+        );
+        assert_eq!(parse_intention("(✨) add feature"), Some(Category::Minor));
+        assert_eq!(
+            parse_intention(":bug: fix the parser"),
+            Some(Category::Patch)
+        );
+    }
+
+    #[test]
+    fn listing_gitmojis_of_category() {
+        // When
+        let result = Category::Major.gitmojis();
+
+        // Then
+        assert!(result.contains(&(":boom:", "💥")));
+        assert!(!result.contains(&(":sparkles:", "✨")));
+        assert!(Category::Minor.gitmojis().contains(&(":sparkles:", "✨")));
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod gitmojis_json_tests {
+    use crate::intention::IntentionMap;
+
+    #[test]
+    fn serializing_intentions_into_gitmojis_json() {
+        // Given
+        let intention_map = IntentionMap::default();
+
+        // When
+        let result = intention_map.to_gitmojis_json().unwrap();
+
+        // Then
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let gitmojis = json["gitmojis"].as_array().unwrap();
+        assert_eq!(gitmojis.len(), intention_map.iter().count());
+        assert!(
+            gitmojis.contains(&serde_json::json!({
+                "emoji": "💥",
+                "code": ":boom:",
+                "name": "boom",
+                "semver": "major",
+                "category": "major",
+            })),
+            "missing :boom: in {result}"
+        );
+    }
+}
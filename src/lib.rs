@@ -1,7 +1,85 @@
+#[cfg(feature = "async")]
+mod async_repo;
+#[cfg(feature = "git")]
 mod changes;
+mod config;
+mod intention;
+mod manifest;
+#[cfg(feature = "git")]
 mod repo;
-#[cfg(any(test, feature = "test_util"))]
+#[cfg(feature = "git")]
+mod report;
+#[cfg(feature = "git")]
+mod summary;
+#[cfg(all(feature = "git", any(test, feature = "test_util")))]
 pub mod test_util;
 
+#[cfg(feature = "async")]
+pub use crate::async_repo::open_repo_with_timeout;
+#[cfg(feature = "git")]
+pub use crate::changes::analyze;
+#[cfg(feature = "git")]
+pub use crate::changes::validate_monotonic;
+#[cfg(feature = "git")]
+pub use crate::changes::ActionOptions;
+#[cfg(feature = "git")]
+pub use crate::changes::ChangeCounts;
+#[cfg(feature = "git")]
 pub use crate::changes::Changes;
+#[cfg(feature = "git")]
+pub use crate::changes::ChangesError;
+#[cfg(feature = "git")]
+pub use crate::changes::Release;
+#[cfg(feature = "git")]
+pub use crate::changes::RepositoryExtension;
+#[cfg(feature = "git")]
 pub use crate::changes::SemanticVersionAction;
+#[cfg(feature = "git")]
+pub use crate::changes::ZeroVerMode;
+pub use crate::config::Config;
+pub use crate::config::CONFIG_FILE_NAME;
+#[cfg(feature = "git")]
+pub use crate::intention::classify_git2_commit;
+pub use crate::intention::parse_intention;
+pub use crate::intention::Category;
+pub use crate::intention::Classify;
+pub use crate::intention::EmojiStyle;
+pub use crate::intention::Gitmoji;
+pub use crate::intention::IntentionMap;
+pub use crate::manifest::read_package_version;
+pub use crate::manifest::write_locked_package_version;
+pub use crate::manifest::write_package_version;
+#[cfg(feature = "git")]
+pub use crate::repo::next_calver;
+#[cfg(feature = "git")]
+pub use crate::repo::normalize_remote_url;
+#[cfg(feature = "git")]
+pub use crate::repo::remote_url;
+#[cfg(feature = "git")]
+pub use crate::repo::retry_on_lock;
+#[cfg(feature = "git")]
+pub use crate::repo::ConventionalCommit;
+#[cfg(feature = "git")]
+pub use crate::repo::DuplicateVersionTag;
+#[cfg(feature = "git")]
+pub use crate::repo::FetchOptions;
+#[cfg(feature = "git")]
+pub use crate::repo::RemoteHost;
+#[cfg(feature = "git")]
+pub use crate::repo::TagKind;
+#[cfg(feature = "git")]
+pub use crate::repo::VersionScheme;
+#[cfg(feature = "git")]
+pub use crate::repo::VersionTag;
+#[cfg(feature = "git")]
+pub use crate::repo::DEFAULT_TAG_PREFIXES;
+#[cfg(feature = "git")]
+pub use crate::repo::LOCK_RETRIES;
+#[cfg(feature = "git")]
+pub use crate::repo::SHORT_HASH_LEN;
+#[cfg(feature = "git")]
+pub use crate::report::Report;
+#[cfg(all(feature = "git", feature = "serde"))]
+pub use crate::summary::json_schema;
+#[cfg(feature = "git")]
+pub use crate::summary::Summary;
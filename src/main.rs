@@ -1,9 +1,27 @@
 extern crate cargo_semantic_release;
-use cargo_semantic_release::Changes;
-use clap::Parser;
+use anstyle::{AnsiColor, Style};
+#[cfg(feature = "serde")]
+use cargo_semantic_release::Summary;
+use cargo_semantic_release::{
+    next_calver, parse_intention, read_package_version, retry_on_lock, validate_monotonic,
+    write_locked_package_version, write_package_version, ActionOptions, Category, ChangeCounts,
+    Changes, Config, ConventionalCommit, EmojiStyle, FetchOptions, IntentionMap, Report,
+    RepositoryExtension, SemanticVersionAction, VersionScheme, ZeroVerMode, CONFIG_FILE_NAME,
+    DEFAULT_TAG_PREFIXES, SHORT_HASH_LEN,
+};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, Datelike};
+use clap::{ArgGroup, ColorChoice, Parser, ValueEnum};
 use clap_cargo::style;
-use git2::Repository;
-use std::{env, process};
+use git2::{Oid, Repository};
+use semver::Version;
+use std::error::Error;
+use std::ffi::OsString;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{env, fs, io, process};
 
 #[derive(Parser)]
 #[command(name = "cargo")]
@@ -15,7 +33,337 @@ enum CargoCli {
 
 #[derive(clap::Args)]
 #[command(version, about, display_name = "semantic-release")]
-struct SemanticReleaseArgs {}
+#[command(group(ArgGroup::new("release").args(["tag", "commit"]).multiple(true)))]
+struct SemanticReleaseArgs {
+    /// Analyze the given branch, tag or revision instead of HEAD.
+    /// When HEAD is detached the default branch is analyzed.
+    #[arg(long = "ref", value_name = "NAME")]
+    reference: Option<String>,
+
+    /// Prefix of the version tags, can be given multiple times.
+    /// Tags with the `v` prefix or without a prefix are recognized by default.
+    #[arg(long = "tag-prefix", value_name = "PREFIX")]
+    tag_prefixes: Vec<String>,
+
+    /// Compare against the latest version tag reachable from the analyzed commit (HEAD or
+    /// --ref) instead of the highest version tag, e.g. on hotfix branches
+    #[arg(long)]
+    reachable_tag: bool,
+
+    /// Analyze the commits of a two-dot revspec like `v1.0.0..HEAD` instead of the commits
+    /// since the latest version tag. Three-dot ranges are not supported
+    #[arg(
+        long,
+        value_name = "REVSPEC",
+        conflicts_with_all = ["reference", "reachable_tag", "recurse_submodules"]
+    )]
+    range: Option<String>,
+
+    /// Only analyze the commits that change files under this path, relative to the current
+    /// directory. The path must be inside the repository
+    #[arg(long, value_name = "PREFIX")]
+    path: Option<PathBuf>,
+
+    /// Read the configuration from this file instead of the .semantic-release.toml file
+    /// in the current directory
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+
+    /// Analyze a member crate of a workspace: only the commits changing the crate
+    /// directory are analyzed and the current version is read from its Cargo.toml
+    #[arg(long, value_name = "DIR", conflicts_with = "path")]
+    manifest_path: Option<PathBuf>,
+
+    /// Only follow the first parent of merge commits, so the commits of merged branches
+    /// are not analyzed on their own
+    #[arg(long)]
+    first_parent: bool,
+
+    /// Also analyze the submodules and merge their changes
+    #[arg(long)]
+    recurse_submodules: bool,
+
+    /// Classify commits with multiple intentions by the highest category instead of the
+    /// first intention
+    #[arg(long)]
+    highest_wins: bool,
+
+    /// Drop the commits of the authors whose name or email contains this text, e.g.
+    /// dependabot[bot], can be given multiple times.
+    /// Dropped commits don't count toward the version change
+    #[arg(long = "exclude-author", value_name = "PATTERN")]
+    exclude_authors: Vec<String>,
+
+    /// Classify each line of the commit messages on its own and choose the highest
+    /// category, e.g. for squash merges listing multiple commits in their body
+    #[arg(long)]
+    scan_body: bool,
+
+    /// Fail if any analyzed commit has no known intention, instead of dropping it
+    #[arg(long, conflicts_with = "keep_unrecognized")]
+    strict: bool,
+
+    /// Drop the commits whose message contains this marker, whatever their intention is.
+    /// An empty marker drops nothing
+    #[arg(long, value_name = "MARKER", default_value = "[skip release]")]
+    skip_marker: String,
+
+    /// Keep the commits without a known intention as other changes instead of dropping them
+    #[arg(long)]
+    keep_unrecognized: bool,
+
+    /// Drop reverted commits together with the commits reverting them
+    #[arg(long)]
+    collapse_reverts: bool,
+
+    /// Increment the patch version when only documentation changed
+    #[arg(long)]
+    docs_trigger_patch: bool,
+
+    /// Increment the patch version when there are only other changes, but the
+    /// latest version tag is older than this many days
+    #[arg(long, value_name = "DAYS")]
+    min_age: Option<u64>,
+
+    /// Produce a pre-release version with this identifier and a counter, e.g. `rc` for
+    /// `1.3.0-rc.1`. The counter is incremented when the current version is already a
+    /// pre-release with the same identifier
+    #[arg(long, value_name = "IDENTIFIER")]
+    pre_release: Option<String>,
+
+    /// Suggest at least this level of version change, whatever the changes are
+    #[arg(long, value_enum, value_name = "LEVEL")]
+    min_bump: Option<BumpLevel>,
+
+    /// How to handle the changes while the major version is 0. In `strict` mode breaking
+    /// changes increment the minor version and features increment the patch version
+    #[arg(long, value_enum, value_name = "MODE", default_value = "loose")]
+    zero_ver: ZeroVer,
+
+    /// Versioning scheme of the version tags. Calendar versions like `2024.05.0` move to the
+    /// current month, or increment their micro number within the same month
+    #[arg(
+        long,
+        value_enum,
+        default_value = "semver",
+        conflicts_with_all = ["reachable_tag", "pre_release"]
+    )]
+    scheme: Scheme,
+
+    /// Suggest this version for the first release, when there are no version tags yet.
+    /// Calendar versions start from the current month instead
+    #[arg(long, value_name = "VERSION", default_value = "0.1.0")]
+    initial_version: Version,
+
+    /// Analyze the changes as if a commit with this message was added, e.g. to check the
+    /// version change of committing the staged changes
+    #[arg(long, value_name = "MSG", conflicts_with = "tag")]
+    preview_message: Option<String>,
+
+    /// Fail instead of warning when the repository is a shallow clone
+    #[arg(long)]
+    deny_shallow: bool,
+
+    /// Read newline separated commit messages from stdin instead of a repository
+    #[arg(
+        long,
+        conflicts_with_all = ["reference", "path", "manifest_path", "min_age", "recurse_submodules", "first_parent", "range"]
+    )]
+    stdin: bool,
+
+    /// Print a complete Markdown changelog with a section for each version tag, from the
+    /// newest to the oldest, after a section of the unreleased changes
+    #[arg(
+        long,
+        conflicts_with_all = ["stdin", "range", "reachable_tag", "recurse_submodules", "format", "count_only", "porcelain", "tag", "commit"]
+    )]
+    all_tags: bool,
+
+    /// Leave the other changes out of the text, Markdown and JSON outputs. They still
+    /// count toward the version change, e.g. with --docs-trigger-patch
+    #[arg(long)]
+    hide_other: bool,
+
+    /// Show at most this many commits of each change category. The counts and the
+    /// version change still include all commits
+    #[arg(long, value_name = "N")]
+    max_per_category: Option<usize>,
+
+    /// Number of characters to show from the commit hashes
+    #[arg(long, value_name = "N", default_value_t = SHORT_HASH_LEN)]
+    hash_len: usize,
+
+    /// Thank the contributors of the changes, in a Contributors section of the Markdown
+    /// changelog
+    #[arg(long)]
+    thanks: bool,
+
+    /// Print the intentions with the change category they belong to and exit
+    #[arg(long)]
+    explain: bool,
+
+    /// Check that the commit message, or the message of HEAD if none is given, starts with
+    /// a known intention and exit with an error otherwise, e.g. in a commit-msg hook
+    #[arg(long, value_name = "MESSAGE", num_args = 0..=1)]
+    lint: Option<Option<String>>,
+
+    /// Print additional details about the changes
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// Only print the action for the semantic version
+    #[arg(short, long, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Only print the number of commits in each change category
+    #[arg(long, conflicts_with = "format")]
+    count_only: bool,
+
+    /// Append the action, the next version, whether the version changes and the changelog
+    /// to the file named by the `GITHUB_OUTPUT` environment variable, e.g. in a GitHub
+    /// Actions step. The outputs are printed when the variable is unset
+    #[arg(long)]
+    github_output: bool,
+
+    /// Print a single line for scripts, which is kept stable across versions:
+    /// `action=<keep|patch|minor|major>\tnext=<version>\tcounts=<major>,<minor>,<patch>,<other>`.
+    /// The next version is empty when it's unknown
+    #[arg(long, conflicts_with_all = ["format", "count_only", "quiet", "verbose"])]
+    porcelain: bool,
+
+    /// When to use colors in the human readable output
+    #[arg(long, value_enum, value_name = "WHEN", default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+
+    /// Create an annotated version tag of the next version on HEAD when the version changes
+    #[arg(long, conflicts_with = "stdin")]
+    tag: bool,
+
+    /// Write the next version into Cargo.toml, and into Cargo.lock if there is one, and
+    /// commit it as the release commit. Fails when the version doesn't change. The release
+    /// commit is tagged when combined with --tag
+    #[arg(long, conflicts_with_all = ["stdin", "preview_message"])]
+    commit: bool,
+
+    /// Create the release commit or the version tag even if the working tree has
+    /// uncommitted changes
+    #[arg(long, requires = "release")]
+    allow_dirty: bool,
+
+    /// Run this command when the version changes. `{version}` is replaced with the
+    /// next version and `{action}` with `major`, `minor` or `patch`
+    #[arg(long, value_name = "CMD")]
+    exec: Option<String>,
+
+    /// Render the intentions of the commits as unicode emoji or as shortcodes, e.g. for
+    /// logs that mangle unicode. The intentions are shown as written by default
+    #[arg(long, value_enum, value_name = "STYLE")]
+    emoji: Option<EmojiFormat>,
+
+    /// Show the author date of the commits in the Markdown changelog, formatted with this
+    /// strftime-style format, e.g. `%Y-%m-%d`
+    #[arg(long, value_name = "FORMAT", value_parser = parse_date_format)]
+    date_format: Option<String>,
+
+    /// Format of the output
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Print the JSON Schema of the JSON output and exit
+    #[cfg(feature = "serde")]
+    #[arg(long, hide = true)]
+    json_schema: bool,
+
+    /// Print the intentions as a gitmojis.json document of the gitmoji project and exit
+    #[cfg(feature = "serde")]
+    #[arg(long)]
+    dump_gitmojis: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum OutputFormat {
+    /// Human readable text
+    Text,
+    /// JSON document, described by the schema printed with --json-schema
+    #[cfg(feature = "serde")]
+    Json,
+    /// Markdown changelog section of the next release
+    Markdown,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum EmojiFormat {
+    Unicode,
+    Shortcode,
+}
+
+impl From<EmojiFormat> for EmojiStyle {
+    fn from(emoji_format: EmojiFormat) -> Self {
+        match emoji_format {
+            EmojiFormat::Unicode => EmojiStyle::Unicode,
+            EmojiFormat::Shortcode => EmojiStyle::Shortcode,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum BumpLevel {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl From<BumpLevel> for SemanticVersionAction {
+    fn from(bump_level: BumpLevel) -> Self {
+        match bump_level {
+            BumpLevel::Patch => SemanticVersionAction::IncrementPatch,
+            BumpLevel::Minor => SemanticVersionAction::IncrementMinor,
+            BumpLevel::Major => SemanticVersionAction::IncrementMajor,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ZeroVer {
+    Strict,
+    Loose,
+}
+
+impl From<ZeroVer> for ZeroVerMode {
+    fn from(zero_ver: ZeroVer) -> Self {
+        match zero_ver {
+            ZeroVer::Strict => ZeroVerMode::Strict,
+            ZeroVer::Loose => ZeroVerMode::Loose,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Scheme {
+    Semver,
+    Calver,
+}
+
+impl From<Scheme> for VersionScheme {
+    fn from(scheme: Scheme) -> Self {
+        match scheme {
+            Scheme::Semver => VersionScheme::SemVer,
+            Scheme::Calver => VersionScheme::CalVer,
+        }
+    }
+}
+
+/// Check that a strftime-style date format is valid, because formatting a date with an
+/// invalid format panics.
+fn parse_date_format(date_format: &str) -> Result<String, String> {
+    if StrftimeItems::new(date_format).any(|item| item == Item::Error) {
+        return Err(format!("invalid date format '{date_format}'"));
+    }
+    Ok(date_format.to_string())
+}
+
+/// Name of the subcommand that cargo passes as the first argument to the binary
+const SUBCOMMAND_NAME: &str = "semantic-release";
 
 pub const CLAP_STYLING: clap::builder::styling::Styles = clap::builder::styling::Styles::styled()
     .header(style::HEADER)
@@ -31,25 +379,912 @@ fn main() {
     // show the version and help information respectively. Then it will exit.
     // When no arguments are found the application will just continue after
     // the parse step.
-    let _ = CargoCli::parse();
+    let CargoCli::SemanticRelease(args) = CargoCli::parse_from(cli_arguments());
+
+    #[cfg(feature = "serde")]
+    if args.json_schema {
+        println!("{}", cargo_semantic_release::json_schema());
+        return;
+    }
+    if args.explain {
+        print_intentions(&IntentionMap::default());
+        return;
+    }
+    if let Some(message) = &args.lint {
+        lint_commit_message(message.as_deref());
+        return;
+    }
+    let text_output = args.format == OutputFormat::Text
+        && !args.count_only
+        && !args.quiet
+        && !args.porcelain
+        && !args.all_tags;
+    match args.color {
+        ColorChoice::Auto => anstream::ColorChoice::Auto,
+        ColorChoice::Always => anstream::ColorChoice::Always,
+        ColorChoice::Never => anstream::ColorChoice::Never,
+    }
+    .write_global();
+
+    let mut intention_map = IntentionMap::default();
+    if let Some(config) = load_config(&args) {
+        config.apply(&mut intention_map).unwrap_or_else(|error| {
+            eprintln!("Error during loading the configuration:\n\t{error}");
+            process::exit(1);
+        });
+    }
+    intention_map.set_highest_wins(args.highest_wins);
+    intention_map.set_scan_body(args.scan_body);
+    intention_map.set_keep_unrecognized(args.keep_unrecognized);
+    #[cfg(feature = "serde")]
+    if args.dump_gitmojis {
+        let json = intention_map.to_gitmojis_json().unwrap_or_else(|error| {
+            eprintln!("Error during serializing the intentions:\n\t{error}");
+            process::exit(1);
+        });
+        println!("{json}");
+        return;
+    }
+
+    let git_repo = (!args.stdin).then(|| open_repository(text_output));
+    if let Some(git_repo) = &git_repo {
+        check_shallow_clone(git_repo, args.deny_shallow);
+        if args.tag || args.commit {
+            check_release_head(git_repo);
+        }
+    }
+    if let Some(git_repo) = git_repo.as_ref().filter(|_| args.all_tags) {
+        print_history(git_repo, &args, &intention_map);
+        return;
+    }
+    let mut changes = match &git_repo {
+        Some(git_repo) => fetch_changes(git_repo, &args, &intention_map, text_output),
+        None => read_changes_from_stdin(&intention_map),
+    };
+    changes.exclude_marked(&args.skip_marker);
+    if args.collapse_reverts {
+        changes.collapse_reverts();
+    }
+    if !args.exclude_authors.is_empty() {
+        changes.exclude_authors(&args.exclude_authors);
+    }
+    if args.strict {
+        check_unrecognized_commits(&changes);
+    }
+    if let Some(message) = &args.preview_message {
+        preview_commit(&mut changes, message, &intention_map);
+    }
+    if args.count_only {
+        println!("{}", changes.counts());
+        return;
+    }
+
+    let tag_prefixes = tag_prefixes(&args);
+    let version_tag = git_repo.as_ref().and_then(|git_repo| {
+        // Calendar versions with a padded month look like malformed semantic versions.
+        if matches!(args.scheme, Scheme::Semver) {
+            warn_about_version_tags(git_repo, &tag_prefixes);
+        }
+        if args.reachable_tag {
+            match analyzed_reference(git_repo, &args, false) {
+                Some(reference) => {
+                    git_repo.latest_version_tag_reachable_from(&reference, &tag_prefixes)
+                }
+                None => git_repo.latest_reachable_version_tag_with_prefixes(&tag_prefixes),
+            }
+        } else {
+            git_repo.latest_version_tag_with_scheme(&tag_prefixes, args.scheme.into())
+        }
+        .unwrap_or_else(|error| {
+            eprintln!("Error during fetching the latest version tag:\n\t{error}");
+            process::exit(1);
+        })
+    });
+    if text_output {
+        let released_head =
+            git_repo
+                .as_ref()
+                .zip(version_tag.as_ref())
+                .filter(|(git_repo, version_tag)| {
+                    changes.counts() == ChangeCounts::default()
+                        && is_head(git_repo, version_tag.commit_oid)
+                });
+        match released_head {
+            Some((_, version_tag)) => {
+                println!(
+                    "Already at the latest release ({})",
+                    tag_name(&args, &version_tag.version)
+                )
+            }
+            None => print_changes(&changes, &args, &intention_map),
+        }
+    }
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64);
+    let release_overdue = args
+        .min_age
+        .zip(version_tag.as_ref())
+        .is_some_and(|(days, version_tag)| version_tag.is_older_than(days, now));
+
+    let manifest_version = match &args.manifest_path {
+        Some(manifest_path) => Some(read_package_version(manifest_path).unwrap_or_else(|error| {
+            eprintln!("Error during reading the package version:\n\t{error}");
+            process::exit(1);
+        })),
+        None if git_repo.is_some() => read_package_version(Path::new(".")).ok(),
+        None => None,
+    };
+    if let Some((manifest_version, version_tag)) =
+        manifest_version.as_ref().zip(version_tag.as_ref())
+    {
+        if *manifest_version != version_tag.version {
+            eprintln!(
+                "Warning: the version in Cargo.toml ({manifest_version}) differs from the latest version tag ({})",
+                version_tag.version
+            );
+        }
+    }
+    let current_version = match &args.manifest_path {
+        Some(_) => manifest_version,
+        None => version_tag.map(|version_tag| version_tag.version),
+    };
+    let action_options = ActionOptions {
+        docs_trigger_patch: args.docs_trigger_patch,
+        release_overdue,
+        min_bump: args.min_bump.map(SemanticVersionAction::from),
+        current_version: current_version.clone(),
+        zero_ver: args.zero_ver.into(),
+    };
+    let action = changes.define_action_with_options(&action_options);
+    let is_initial_release = current_version.is_none() && git_repo.is_some();
+    let (year, month) = DateTime::from_timestamp(now, 0)
+        .map_or((1970, 1), |date| (date.year() as u64, date.month() as u64));
+    let next_version = current_version.as_ref().map(|current_version| {
+        let next_version = match (&args.pre_release, args.scheme) {
+            (_, Scheme::Calver) if action == SemanticVersionAction::Keep => {
+                Ok(current_version.clone())
+            }
+            (_, Scheme::Calver) => Ok(next_calver(current_version, year, month)),
+            (Some(identifier), Scheme::Semver) => {
+                action.next_pre_release(current_version, identifier)
+            }
+            (None, Scheme::Semver) => Ok(action.next_version(current_version)),
+        }
+        .and_then(|next_version| {
+            validate_monotonic(current_version, &next_version, &action)?;
+            Ok(next_version)
+        });
+        next_version.unwrap_or_else(|error| {
+            eprintln!("Error during computing the next version:\n\t{error}");
+            process::exit(1);
+        })
+    });
+    let next_version = next_version.or_else(|| {
+        is_initial_release.then(|| match args.scheme {
+            Scheme::Semver => args.initial_version.clone(),
+            Scheme::Calver => Version::new(year, month, 0),
+        })
+    });
 
+    // The other changes still count toward the action, they are only left out of the output.
+    let mut shown_changes = changes.clone();
+    if args.hide_other {
+        shown_changes.remove_category(Category::Other);
+    }
+    match args.format {
+        _ if args.porcelain => {
+            let counts = changes.counts();
+            println!(
+                "action={}\tnext={}\tcounts={},{},{},{}",
+                action_name(action),
+                next_version
+                    .as_ref()
+                    .map(|next_version| version_name(&args, next_version))
+                    .unwrap_or_default(),
+                counts.major,
+                counts.minor,
+                counts.patch,
+                counts.other
+            );
+        }
+        OutputFormat::Text => {
+            println!("Action for semantic version ➡️ {action}");
+            if !args.quiet {
+                print_trigger_and_next_version(
+                    &changes,
+                    action,
+                    next_version.as_ref().filter(|_| !is_initial_release),
+                    &args,
+                    &intention_map,
+                );
+                if let Some(initial_version) = next_version.as_ref().filter(|_| is_initial_release)
+                {
+                    println!(
+                        "No version tags yet, initial version ➡️ {}",
+                        version_name(&args, initial_version)
+                    );
+                }
+            }
+        }
+        #[cfg(feature = "serde")]
+        OutputFormat::Json => {
+            let summary = Summary {
+                next_version: next_version
+                    .as_ref()
+                    .map(|next_version| version_name(&args, next_version)),
+                ..Summary::new(&shown_changes, action, current_version.as_ref())
+            };
+            let json = summary.to_json().unwrap_or_else(|error| {
+                eprintln!("Error during serializing the summary:\n\t{error}");
+                process::exit(1);
+            });
+            println!("{json}");
+        }
+        OutputFormat::Markdown => {
+            let changelog = changelog(
+                &shown_changes,
+                action,
+                next_version.as_ref(),
+                &args,
+                &intention_map,
+            );
+            print!("{changelog}");
+        }
+    }
+    if args.github_output {
+        let changelog = changelog(
+            &shown_changes,
+            action,
+            next_version.as_ref(),
+            &args,
+            &intention_map,
+        );
+        write_github_output(action, next_version.as_ref(), &changelog, &args);
+    }
+
+    if let Some(git_repo) = git_repo.as_ref().filter(|_| args.commit) {
+        create_release_commit(git_repo, &args, action, next_version.as_ref());
+    }
+    if let Some(git_repo) = git_repo.as_ref().filter(|_| args.tag) {
+        if action != SemanticVersionAction::Keep {
+            create_version_tag(git_repo, &args, next_version.as_ref());
+        }
+    }
+    if let Some(command) = &args.exec {
+        if action != SemanticVersionAction::Keep {
+            run_command(
+                command,
+                action,
+                next_version
+                    .as_ref()
+                    .map(|next_version| version_name(&args, next_version)),
+            );
+        }
+    }
+}
+
+fn print_trigger_and_next_version(
+    changes: &Changes,
+    action: SemanticVersionAction,
+    next_version: Option<&Version>,
+    args: &SemanticReleaseArgs,
+    intention_map: &IntentionMap,
+) {
+    if let Some((commit, _)) = changes
+        .trigger()
+        .filter(|_| action != SemanticVersionAction::Keep)
+    {
+        println!(
+            "\ttriggered by {}: {}",
+            commit.short_hash(),
+            render_intentions(commit.subject(), args, intention_map)
+        );
+    }
+    if let Some(next_version) = next_version {
+        println!("Next version ➡️ {}", version_name(args, next_version));
+    }
+}
+
+fn print_intentions(intention_map: &IntentionMap) {
+    let shortcode_width = intention_map
+        .iter()
+        .map(|(shortcode, _, _)| shortcode.len())
+        .max()
+        .unwrap_or_default();
+    println!("{:shortcode_width$}  {:5}  category", "shortcode", "emoji");
+    for (shortcode, emoji, category) in intention_map.iter() {
+        println!("{shortcode:shortcode_width$}  {emoji:5}  {category}");
+    }
+}
+
+/// Print the category of the intention of the commit message, or exit with an error if
+/// the intention is unrecognized.
+fn lint_commit_message(message: Option<&str>) {
+    let message = match message {
+        Some(message) => message.to_string(),
+        None => {
+            let git_repo = open_repository(false);
+            let head_message = git_repo
+                .head()
+                .and_then(|head| head.peel_to_commit())
+                .map(|commit| String::from_utf8_lossy(commit.message_bytes()).into_owned());
+            head_message.unwrap_or_else(|error| {
+                eprintln!("Error during reading the commit message of HEAD:\n\t{error}");
+                process::exit(1);
+            })
+        }
+    };
+    let subject = message.lines().next().unwrap_or_default().trim();
+    match parse_intention(&message) {
+        Some(category) => println!("Recognized {category} intention: {subject}"),
+        None => {
+            eprintln!("Unrecognized intention: {subject}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Render the Markdown changelog section of the next release.
+fn changelog(
+    changes: &Changes,
+    action: SemanticVersionAction,
+    next_version: Option<&Version>,
+    args: &SemanticReleaseArgs,
+    intention_map: &IntentionMap,
+) -> String {
+    let mut report = Report {
+        action,
+        next_version: next_version.cloned(),
+        ..changes.report(None)
+    };
+    for (_, subject, _, _) in &mut report.entries {
+        *subject = render_intentions(subject, args, intention_map);
+    }
+    let mut changelog = match (next_version, &args.date_format) {
+        (Some(next_version), date_format) => report
+            .to_named_changelog_markdown(&tag_name(args, next_version), date_format.as_deref()),
+        (None, Some(date_format)) => report.to_changelog_markdown_with_dates(date_format),
+        (None, None) => report.to_changelog_markdown(),
+    };
+    if args.thanks && action != SemanticVersionAction::Keep {
+        changelog.push_str(&contributors_markdown(changes));
+    }
+    changelog
+}
+
+/// Render the contributors of the changes as a Markdown changelog subsection, for --thanks.
+fn contributors_markdown(changes: &Changes) -> String {
+    let mut markdown = "\n### Contributors\n\n".to_string();
+    for (name, email) in changes.contributors() {
+        markdown.push_str(&format!("- {name} <{email}>\n"));
+    }
+    markdown
+}
+
+/// Append the outputs of the analysis to the file named by `GITHUB_OUTPUT`, or print them
+/// if it's unset, in the `name=value` syntax of GitHub Actions. The multiline changelog is
+/// written between delimiter lines that don't occur in it.
+fn write_github_output(
+    action: SemanticVersionAction,
+    next_version: Option<&Version>,
+    changelog: &str,
+    args: &SemanticReleaseArgs,
+) {
+    let mut delimiter = "EOF".to_string();
+    while changelog.lines().any(|line| line == delimiter) {
+        delimiter.push('_');
+    }
+    let outputs = format!(
+        "action={}\nnext-version={}\nchanged={}\nchangelog<<{delimiter}\n{}\n{delimiter}\n",
+        action_name(action),
+        next_version
+            .map(|next_version| version_name(args, next_version))
+            .unwrap_or_default(),
+        action != SemanticVersionAction::Keep,
+        changelog.trim_end_matches('\n'),
+    );
+
+    let Some(path) = env::var_os("GITHUB_OUTPUT").filter(|path| !path.is_empty()) else {
+        print!("{outputs}");
+        return;
+    };
+    let write = || -> io::Result<()> {
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        file.write_all(outputs.as_bytes())
+    };
+    write().unwrap_or_else(|error| {
+        eprintln!(
+            "Error during writing the GitHub output to {}:\n\t{error}",
+            Path::new(&path).display()
+        );
+        process::exit(1);
+    });
+}
+
+/// Name of the version change of an action, like it's given to --min-bump.
+fn action_name(action: SemanticVersionAction) -> &'static str {
+    match action {
+        SemanticVersionAction::IncrementMajor => "major",
+        SemanticVersionAction::IncrementMinor => "minor",
+        SemanticVersionAction::IncrementPatch => "patch",
+        SemanticVersionAction::Keep => "keep",
+    }
+}
+
+fn run_command(command: &str, action: SemanticVersionAction, next_version: Option<String>) {
+    let mut command = command.replace("{action}", action_name(action));
+    if command.contains("{version}") {
+        let Some(next_version) = next_version else {
+            eprintln!("Error during running the command:\n\tthe next version is unknown without a version tag");
+            process::exit(1);
+        };
+        command = command.replace("{version}", &next_version);
+    }
+
+    let status = if cfg!(windows) {
+        Command::new("cmd").args(["/C", &command]).status()
+    } else {
+        Command::new("sh").args(["-c", &command]).status()
+    };
+    let status = status.unwrap_or_else(|error| {
+        eprintln!("Error during running the command:\n\t{error}");
+        process::exit(1);
+    });
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
+}
+
+fn create_release_commit(
+    git_repo: &Repository,
+    args: &SemanticReleaseArgs,
+    action: SemanticVersionAction,
+    next_version: Option<&Version>,
+) {
+    let create_commit = || -> Result<Oid, Box<dyn Error>> {
+        if action == SemanticVersionAction::Keep {
+            return Err("there are no changes to release".into());
+        }
+        let next_version =
+            next_version.ok_or("the next version is unknown without a version tag")?;
+        if !args.allow_dirty && !git_repo.is_clean()? {
+            return Err(
+                "the working tree has uncommitted changes, commit them or use --allow-dirty".into(),
+            );
+        }
+        let crate_dir = args.manifest_path.as_deref().unwrap_or(Path::new("."));
+        write_package_version(crate_dir, next_version)?;
+        let crate_path = repository_path(git_repo, crate_dir, "manifest path");
+        let mut changed_paths = vec![crate_path.join("Cargo.toml")];
+
+        // The lock file of the crate, or of its workspace, also records the package version.
+        let workdir = git_repo.workdir().ok_or("the repository is bare")?;
+        let lockfile_path = crate_path
+            .ancestors()
+            .map(|dir| dir.join("Cargo.lock"))
+            .find(|lockfile_path| workdir.join(lockfile_path).is_file());
+        if let Some(lockfile_path) = lockfile_path {
+            write_locked_package_version(&workdir.join(&lockfile_path), crate_dir, next_version)?;
+            if !git_repo.is_path_ignored(&lockfile_path)? {
+                changed_paths.push(lockfile_path);
+            }
+        }
+
+        let mut index = git_repo.index()?;
+        for changed_path in &changed_paths {
+            index.add_path(changed_path)?;
+        }
+        retry_on_lock(|| index.write())?;
+        let tree = git_repo.find_tree(index.write_tree()?)?;
+        let head = git_repo.head()?.peel_to_commit()?;
+        let signature = git_repo.signature()?;
+        let commit_oid = retry_on_lock(|| {
+            git_repo.commit(
+                Some("HEAD"),
+                &signature,
+                &signature,
+                &format!(":bookmark: release {}", tag_name(args, next_version)),
+                &tree,
+                &[&head],
+            )
+        })?;
+        Ok(commit_oid)
+    };
+    match create_commit() {
+        Ok(commit_oid) => println!("Created release commit {commit_oid}"),
+        Err(error) => {
+            eprintln!("Error during creating the release commit:\n\t{error}");
+            process::exit(1);
+        }
+    }
+}
+
+fn create_version_tag(
+    git_repo: &Repository,
+    args: &SemanticReleaseArgs,
+    next_version: Option<&Version>,
+) {
+    let create_tag = || -> Result<String, Box<dyn Error>> {
+        let next_version =
+            next_version.ok_or("the next version is unknown without a version tag")?;
+        if !args.allow_dirty && !git_repo.is_clean()? {
+            return Err(
+                "the working tree has uncommitted changes, commit them or use --allow-dirty".into(),
+            );
+        }
+        let tag_name = tag_name(args, next_version);
+        let head = git_repo.head()?.peel_to_commit()?;
+        let signature = git_repo.signature()?;
+        retry_on_lock(|| {
+            git_repo.tag(
+                &tag_name,
+                head.as_object(),
+                &signature,
+                &format!("Release {tag_name}"),
+                false,
+            )
+        })?;
+        Ok(tag_name)
+    };
+    match create_tag() {
+        Ok(tag_name) => println!("Created tag {tag_name}"),
+        Err(error) => {
+            eprintln!("Error during creating the version tag:\n\t{error}");
+            process::exit(1);
+        }
+    }
+}
+
+fn open_repository(text_output: bool) -> Repository {
     let path = env::current_dir().unwrap_or_else(|error| {
         eprintln!("Error during getting the current directory:\n\t{error}");
         process::exit(1);
     });
-    println!("Current directory: {}", path.display());
+    if text_output {
+        println!("Current directory: {}", path.display());
+    }
 
-    let git_repo = Repository::open(path).unwrap_or_else(|error| {
+    Repository::open(path).unwrap_or_else(|error| {
         eprintln!("Error during opening repository:\n\t{error}");
         process::exit(1);
+    })
+}
+
+/// Collect the command line arguments, inserting the `semantic-release` subcommand when
+/// the binary is run directly as `cargo-semantic-release` instead of through cargo.
+fn cli_arguments() -> Vec<OsString> {
+    let mut arguments: Vec<OsString> = env::args_os().collect();
+    if arguments
+        .get(1)
+        .is_none_or(|argument| argument != SUBCOMMAND_NAME)
+    {
+        arguments.insert(1.min(arguments.len()), SUBCOMMAND_NAME.into());
+    }
+    arguments
+}
+
+/// Load the configuration file given with `--config`, or the default configuration file
+/// if it exists.
+fn load_config(args: &SemanticReleaseArgs) -> Option<Config> {
+    let config_path = match &args.config {
+        Some(config_path) => config_path.clone(),
+        None => Some(PathBuf::from(CONFIG_FILE_NAME)).filter(|path| path.exists())?,
+    };
+    let config = Config::load(&config_path).unwrap_or_else(|error| {
+        eprintln!("Error during loading the configuration:\n\t{error}");
+        process::exit(1);
     });
+    Some(config)
+}
+
+/// Warn about shallow clones, because the version tags may be missing from their history,
+/// so too many commits would be analyzed.
+fn check_shallow_clone(git_repo: &Repository, deny_shallow: bool) {
+    if !git_repo.is_shallow() {
+        return;
+    }
+    if deny_shallow {
+        eprintln!("Error during opening repository:\n\tthe repository is a shallow clone");
+        process::exit(1);
+    }
+    eprintln!(
+        "Warning: the repository is a shallow clone, the results may be incomplete. \
+        Fetch the full history, e.g. with `git fetch --unshallow`"
+    );
+}
+
+/// Refuse to release on a detached HEAD, where the default branch is analyzed but the
+/// release commit and the version tag would be created on HEAD.
+fn check_release_head(git_repo: &Repository) {
+    if git_repo.head_detached().unwrap_or(false) {
+        eprintln!(
+            "Error during creating the release:\n\tHEAD is detached, check out the branch to release"
+        );
+        process::exit(1);
+    }
+}
 
-    let changes = Changes::try_from(&git_repo).unwrap_or_else(|error| {
+/// Get the path of a file or directory relative to the root of the repository, e.g. of the
+/// crate directory of --manifest-path. The `name` of the argument is used in the error message.
+fn repository_path(git_repo: &Repository, path: &Path, name: &str) -> PathBuf {
+    let resolve = || -> Result<PathBuf, Box<dyn Error>> {
+        let workdir = git_repo
+            .workdir()
+            .ok_or("the repository is bare")?
+            .canonicalize()?;
+        let path = path
+            .canonicalize()
+            .map_err(|error| format!("{}: {error}", path.display()))?;
+        let relative_path = path
+            .strip_prefix(&workdir)
+            .map_err(|_| format!("{} is outside of the repository", path.display()))?;
+        Ok(relative_path.to_path_buf())
+    };
+    resolve().unwrap_or_else(|error| {
+        eprintln!("Error during resolving the {name}:\n\t{error}");
+        process::exit(1);
+    })
+}
+
+fn fetch_changes(
+    git_repo: &Repository,
+    args: &SemanticReleaseArgs,
+    intention_map: &IntentionMap,
+    text_output: bool,
+) -> Changes {
+    let fetch_options = fetch_options(git_repo, args, text_output);
+    let changes = if let Some(range) = &args.range {
+        Changes::from_repo_range(git_repo, range, &fetch_options, intention_map)
+    } else if args.recurse_submodules {
+        Changes::from_repo_recursive(git_repo, &fetch_options, intention_map)
+    } else {
+        Changes::from_repo_with_intentions(git_repo, &fetch_options, intention_map)
+    };
+    changes.unwrap_or_else(|error| {
         eprintln!("Error during fetching changes from repository:\n\t{error}");
         process::exit(1);
-    });
-    println!("Changes in the repository:\n{changes}");
+    })
+}
+
+/// Print the changelog of every release and of the unreleased changes.
+fn print_history(git_repo: &Repository, args: &SemanticReleaseArgs, intention_map: &IntentionMap) {
+    let fetch_options = fetch_options(git_repo, args, false);
+    let history =
+        Changes::history(git_repo, &fetch_options, intention_map).unwrap_or_else(|error| {
+            eprintln!("Error during fetching changes from repository:\n\t{error}");
+            process::exit(1);
+        });
+
+    println!("# Changelog");
+    for (version_tag, mut changes) in history {
+        changes.exclude_marked(&args.skip_marker);
+        if args.collapse_reverts {
+            changes.collapse_reverts();
+        }
+        if !args.exclude_authors.is_empty() {
+            changes.exclude_authors(&args.exclude_authors);
+        }
+        if args.hide_other {
+            changes.remove_category(Category::Other);
+        }
+        if version_tag.is_none() && changes.counts() == ChangeCounts::default() {
+            continue;
+        }
+        let mut report = changes.report(None);
+        for (_, subject, _, _) in &mut report.entries {
+            *subject = render_intentions(subject, args, intention_map);
+        }
+        let version_name = version_tag.map(|version_tag| tag_name(args, &version_tag.version));
+        println!();
+        print!(
+            "{}",
+            report.to_release_markdown(version_name.as_deref(), args.date_format.as_deref())
+        );
+        if args.thanks {
+            print!("{}", contributors_markdown(&changes));
+        }
+    }
+}
+
+fn fetch_options(
+    git_repo: &Repository,
+    args: &SemanticReleaseArgs,
+    text_output: bool,
+) -> FetchOptions {
+    let reference = analyzed_reference(git_repo, args, text_output);
+    let path = match &args.manifest_path {
+        Some(manifest_path) => Some(repository_path(git_repo, manifest_path, "manifest path")),
+        None => args
+            .path
+            .as_deref()
+            .map(|path| repository_path(git_repo, path, "path")),
+    };
+    FetchOptions {
+        reference,
+        path,
+        tag_prefixes: tag_prefixes(args),
+        reachable_tags_only: args.reachable_tag,
+        first_parent: args.first_parent,
+        scheme: args.scheme.into(),
+        ..Default::default()
+    }
+}
+
+/// Get the reference to analyze: the one of --ref, or the default branch on a detached HEAD.
+/// `None` means HEAD.
+fn analyzed_reference(
+    git_repo: &Repository,
+    args: &SemanticReleaseArgs,
+    text_output: bool,
+) -> Option<String> {
+    args.reference.clone().or_else(|| {
+        if !git_repo.head_detached().unwrap_or(false) {
+            return None;
+        }
+        let default_branch = git_repo.default_branch().ok()?;
+        if text_output {
+            println!("HEAD is detached, analyzing the default branch: {default_branch}");
+        }
+        Some(default_branch)
+    })
+}
+
+/// Format a version like it's written in the version tags of the chosen scheme.
+fn version_name(args: &SemanticReleaseArgs, version: &Version) -> String {
+    VersionScheme::from(args.scheme).format(version)
+}
+
+/// Name of the version tag of a version, with the first of the tag prefixes.
+fn tag_name(args: &SemanticReleaseArgs, version: &Version) -> String {
+    let tag_prefix = tag_prefixes(args).into_iter().next().unwrap_or_default();
+    format!("{tag_prefix}{}", version_name(args, version))
+}
+
+fn tag_prefixes(args: &SemanticReleaseArgs) -> Vec<String> {
+    if args.tag_prefixes.is_empty() {
+        DEFAULT_TAG_PREFIXES.map(String::from).to_vec()
+    } else {
+        args.tag_prefixes.clone()
+    }
+}
+
+fn warn_about_version_tags(git_repo: &Repository, tag_prefixes: &[String]) {
+    let (duplicates, malformed_tags) = git_repo
+        .find_duplicate_version_tags(tag_prefixes)
+        .and_then(|duplicates| {
+            let malformed_tags = git_repo.find_malformed_version_tags(tag_prefixes)?;
+            Ok((duplicates, malformed_tags))
+        })
+        .unwrap_or_else(|error| {
+            eprintln!("Error during checking the version tags:\n\t{error}");
+            process::exit(1);
+        });
+    for tag_name in malformed_tags {
+        eprintln!("Warning: skipping tag {tag_name}, its version is not a valid semantic version");
+    }
+    for duplicate in duplicates {
+        eprintln!(
+            "Warning: version {} is tagged multiple times: {}",
+            duplicate.version,
+            duplicate.reference_names.join(", ")
+        );
+    }
+}
+
+fn is_head(git_repo: &Repository, commit_oid: Oid) -> bool {
+    git_repo
+        .head()
+        .and_then(|head| head.peel_to_commit())
+        .is_ok_and(|head_commit| head_commit.id() == commit_oid)
+}
+
+fn read_changes_from_stdin(intention_map: &IntentionMap) -> Changes {
+    let messages = io::stdin()
+        .lines()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap_or_else(|error| {
+            eprintln!("Error during reading commit messages from stdin:\n\t{error}");
+            process::exit(1);
+        });
+    Changes::from_messages_with_intentions(messages, intention_map)
+}
+
+/// Exit with an error listing the commits without a known intention, if there are any.
+fn check_unrecognized_commits(changes: &Changes) {
+    let unrecognized = changes.unrecognized();
+    if unrecognized.is_empty() {
+        return;
+    }
+    eprintln!("Error during checking the commits:\n\tcommits without a known intention:");
+    for commit in unrecognized {
+        eprintln!("\t\t{} {}", commit.short_hash(), commit.subject());
+    }
+    process::exit(1);
+}
+
+/// Add a commit with the given message to the changes, to preview the version change of
+/// committing it.
+fn preview_commit(changes: &mut Changes, message: &str, intention_map: &IntentionMap) {
+    if intention_map.category_of(message).is_none() {
+        eprintln!(
+            "Warning: the preview message has no known intention, it doesn't change the version"
+        );
+    }
+    changes.append_commit_with_intentions(
+        ConventionalCommit {
+            message: message.to_string(),
+            ..Default::default()
+        },
+        intention_map,
+    );
+}
+
+/// Render the intentions of a text in the style chosen with `--emoji`, or keep them as
+/// written.
+fn render_intentions(
+    text: &str,
+    args: &SemanticReleaseArgs,
+    intention_map: &IntentionMap,
+) -> String {
+    match args.emoji {
+        Some(emoji_format) => intention_map.render(text, emoji_format.into()),
+        None => text.to_string(),
+    }
+}
+
+fn print_changes(changes: &Changes, args: &SemanticReleaseArgs, intention_map: &IntentionMap) {
+    println!("Changes in the repository:");
+    for (category, row) in changes.counts().table_rows() {
+        if args.hide_other && category == Category::Other {
+            continue;
+        }
+        let style = category_style(category);
+        anstream::println!("{style}{row}{style:#}");
+        let commits = changes.commits(category);
+        let shown_commits = args.max_per_category.unwrap_or(commits.len());
+        for commit in commits.iter().take(shown_commits) {
+            let line = commit.format_commit(args.hash_len);
+            println!("    {}", render_intentions(&line, args, intention_map));
+        }
+        if commits.len() > shown_commits {
+            println!("    ...and {} more", commits.len() - shown_commits);
+        }
+    }
+    if args.verbose {
+        let analyzed = changes.total_commits_analyzed();
+        let classified = changes.into_iter().count();
+        println!(
+            "Analyzed {analyzed} commits, classified {classified}, skipped {}.",
+            analyzed.saturating_sub(classified)
+        );
+        println!("Changes by scope:");
+        for (scope, counts) in changes.counts_by_scope() {
+            let scope = if scope.is_empty() {
+                "(no scope)"
+            } else {
+                &scope
+            };
+            println!("\t{scope}: {counts}");
+        }
+    }
+
+    if args.thanks {
+        println!("Thanks to the contributors:");
+        for (name, email) in changes.contributors() {
+            println!("\t{name} <{email}>");
+        }
+    }
+}
 
-    let action = changes.define_action_for_semantic_version();
-    println!("Action for semantic version ➡️ {action}");
+fn category_style(category: Category) -> Style {
+    match category {
+        Category::Major => AnsiColor::Red.on_default().bold(),
+        Category::Minor => AnsiColor::Yellow.on_default().bold(),
+        Category::Patch => AnsiColor::Green.on_default().bold(),
+        Category::Other => Style::new().dimmed(),
+    }
 }
@@ -0,0 +1,247 @@
+use semver::Version;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+use toml_edit::{DocumentMut, Item, Value};
+
+/// Read the version of the package from the `Cargo.toml` manifest in a crate directory.
+///
+/// Only the `version` key of the `[package]` table is read, versions inherited from the
+/// workspace (`version.workspace = true`) are reported as an error.
+/// ## Returns
+/// The [`Version`] of the package.
+///
+/// ## Example
+///
+/// ```
+/// use cargo_semantic_release::read_package_version;
+/// use std::path::Path;
+///
+/// let version = read_package_version(Path::new(".")).unwrap();
+/// println!("current version: {version}");
+/// ```
+pub fn read_package_version(crate_dir: &Path) -> Result<Version, Box<dyn Error>> {
+    let manifest_path = crate_dir.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .map_err(|error| format!("can't read {}: {error}", manifest_path.display()))?;
+    let document: DocumentMut = manifest
+        .parse()
+        .map_err(|error| format!("invalid manifest {}: {error}", manifest_path.display()))?;
+
+    let version_item = document
+        .get("package")
+        .and_then(|package| package.get("version"))
+        .ok_or_else(|| format!("no package version in {}", manifest_path.display()))?;
+    if version_item.get("workspace").is_some() {
+        return Err(format!(
+            "the package version in {} is inherited from the workspace, which is not supported",
+            manifest_path.display()
+        )
+        .into());
+    }
+    let version = version_item
+        .as_str()
+        .ok_or_else(|| format!("no package version in {}", manifest_path.display()))?;
+    Version::parse(version).map_err(|error| {
+        format!(
+            "invalid package version in {}: {error}",
+            manifest_path.display()
+        )
+        .into()
+    })
+}
+
+/// Write the version of the package into the `Cargo.toml` manifest in a crate directory.
+///
+/// The rest of the manifest is kept as it is, including its formatting and comments. Like
+/// [`read_package_version`], versions inherited from the workspace are not supported.
+pub fn write_package_version(crate_dir: &Path, version: &Version) -> Result<(), Box<dyn Error>> {
+    let manifest_path = crate_dir.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .map_err(|error| format!("can't read {}: {error}", manifest_path.display()))?;
+    let mut document: DocumentMut = manifest
+        .parse()
+        .map_err(|error| format!("invalid manifest {}: {error}", manifest_path.display()))?;
+
+    let version_value = document
+        .get_mut("package")
+        .and_then(|package| package.get_mut("version"))
+        .and_then(Item::as_value_mut)
+        .filter(|version_value| version_value.is_str())
+        .ok_or_else(|| format!("no package version in {}", manifest_path.display()))?;
+    let decor = version_value.decor().clone();
+    *version_value = Value::from(version.to_string());
+    *version_value.decor_mut() = decor;
+
+    fs::write(&manifest_path, document.to_string())
+        .map_err(|error| format!("can't write {}: {error}", manifest_path.display()))?;
+    Ok(())
+}
+
+/// Write the version of the package of a crate directory into a `Cargo.lock` file, e.g. the
+/// lock file of its workspace, so it stays in sync with [`write_package_version`].
+///
+/// The package is looked up by the name in its `Cargo.toml` manifest, among the packages of
+/// the lock file without a `source`, i.e. the local ones. The rest of the lock file is kept
+/// as it is.
+pub fn write_locked_package_version(
+    lockfile_path: &Path,
+    crate_dir: &Path,
+    version: &Version,
+) -> Result<(), Box<dyn Error>> {
+    let manifest_path = crate_dir.join("Cargo.toml");
+    let manifest: DocumentMut = fs::read_to_string(&manifest_path)
+        .map_err(|error| format!("can't read {}: {error}", manifest_path.display()))?
+        .parse()
+        .map_err(|error| format!("invalid manifest {}: {error}", manifest_path.display()))?;
+    let package_name = manifest
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(Item::as_str)
+        .ok_or_else(|| format!("no package name in {}", manifest_path.display()))?;
+
+    let lockfile = fs::read_to_string(lockfile_path)
+        .map_err(|error| format!("can't read {}: {error}", lockfile_path.display()))?;
+    let mut document: DocumentMut = lockfile
+        .parse()
+        .map_err(|error| format!("invalid lock file {}: {error}", lockfile_path.display()))?;
+    let version_value = document
+        .get_mut("package")
+        .and_then(Item::as_array_of_tables_mut)
+        .and_then(|packages| {
+            packages.iter_mut().find(|package| {
+                package.get("name").and_then(Item::as_str) == Some(package_name)
+                    && !package.contains_key("source")
+            })
+        })
+        .and_then(|package| package.get_mut("version"))
+        .and_then(Item::as_value_mut)
+        .ok_or_else(|| format!("no package {package_name} in {}", lockfile_path.display()))?;
+    let decor = version_value.decor().clone();
+    *version_value = Value::from(version.to_string());
+    *version_value.decor_mut() = decor;
+
+    fs::write(lockfile_path, document.to_string())
+        .map_err(|error| format!("can't write {}: {error}", lockfile_path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod manifest_tests {
+    use crate::manifest::{
+        read_package_version, write_locked_package_version, write_package_version,
+    };
+    use semver::Version;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn reading_package_version() {
+        // Given
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"foo\"\nversion = \"1.2.3\"\n\n[dependencies]\nbar = { version = \"0.1.0\" }\n",
+        )
+        .unwrap();
+
+        // When
+        let result = read_package_version(temp_dir.path()).unwrap();
+
+        // Then
+        assert_eq!(result, Version::new(1, 2, 3));
+    }
+
+    #[test]
+    fn reading_inherited_package_version() {
+        // Given
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(
+            temp_dir.path().join("Cargo.toml"),
+            "[package]\nname = \"foo\"\nversion.workspace = true\n",
+        )
+        .unwrap();
+
+        // When
+        let result = read_package_version(temp_dir.path());
+
+        // Then
+        let error = result.expect_err("Expected an error, but got Ok");
+        assert!(
+            error.to_string().contains("inherited from the workspace"),
+            "error message doesn't explain the inherited version: {error}"
+        );
+    }
+
+    #[test]
+    fn reading_package_version_in_other_toml_syntaxes() {
+        for manifest in [
+            "package = { name = \"foo\", version = \"1.2.3\" }\n",
+            "[package]  # the crate\nname = \"foo\"\nversion = '1.2.3'\n",
+            "[package]\nname = \"foo\"\nversion    =    \"1.2.3\"\n",
+        ] {
+            // Given
+            let temp_dir = TempDir::new().unwrap();
+            fs::write(temp_dir.path().join("Cargo.toml"), manifest).unwrap();
+
+            // When
+            let result = read_package_version(temp_dir.path());
+
+            // Then
+            assert_eq!(
+                result.ok(),
+                Some(Version::new(1, 2, 3)),
+                "wrong version of {manifest:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn writing_package_version() {
+        // Given
+        let temp_dir = TempDir::new().unwrap();
+        let manifest_path = temp_dir.path().join("Cargo.toml");
+        fs::write(
+            &manifest_path,
+            "[package]\nname = \"foo\"\nversion = \"1.2.3\" # released by CI\n\n[dependencies]\nbar = { version = \"0.1.0\" }\n",
+        )
+        .unwrap();
+
+        // When
+        write_package_version(temp_dir.path(), &Version::new(1, 3, 0)).unwrap();
+
+        // Then
+        assert_eq!(
+            fs::read_to_string(manifest_path).unwrap(),
+            "[package]\nname = \"foo\"\nversion = \"1.3.0\" # released by CI\n\n[dependencies]\nbar = { version = \"0.1.0\" }\n"
+        );
+    }
+
+    #[test]
+    fn writing_locked_package_version() {
+        // Given
+        let temp_dir = TempDir::new().unwrap();
+        let crate_dir = temp_dir.path().join("foo");
+        fs::create_dir(&crate_dir).unwrap();
+        fs::write(
+            crate_dir.join("Cargo.toml"),
+            "[package]\nname = \"foo\"\nversion = \"1.3.0\"\n",
+        )
+        .unwrap();
+        let lockfile_path = temp_dir.path().join("Cargo.lock");
+        fs::write(
+            &lockfile_path,
+            "version = 4\n\n[[package]]\nname = \"foo\"\nversion = \"1.2.3\"\n\n[[package]]\nname = \"foo\"\nversion = \"0.1.0\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\n",
+        )
+        .unwrap();
+
+        // When
+        write_locked_package_version(&lockfile_path, &crate_dir, &Version::new(1, 3, 0)).unwrap();
+
+        // Then
+        assert_eq!(
+            fs::read_to_string(lockfile_path).unwrap(),
+            "version = 4\n\n[[package]]\nname = \"foo\"\nversion = \"1.3.0\"\n\n[[package]]\nname = \"foo\"\nversion = \"0.1.0\"\nsource = \"registry+https://github.com/rust-lang/crates.io-index\"\n"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use git2::{BranchType, Repository};
+use std::error::Error;
+
+/// Get the name of the default branch of the repository.
+///
+/// The default branch is the branch that `refs/remotes/origin/HEAD` points to. If the
+/// repository doesn't have such a reference then the local `main` or `master` branch is used.
+/// ## Returns
+/// The name of the default branch which can be resolved as a revision, e.g. `origin/main`.
+pub fn default_branch(repository: &Repository) -> Result<String, Box<dyn Error>> {
+    if let Ok(remote_head) = repository.find_reference("refs/remotes/origin/HEAD") {
+        if let Some(target) = remote_head.symbolic_target() {
+            return Ok(target.trim_start_matches("refs/remotes/").to_string());
+        }
+    }
+
+    ["main", "master"]
+        .into_iter()
+        .find(|name| repository.find_branch(name, BranchType::Local).is_ok())
+        .map(|name| name.to_string())
+        .ok_or_else(|| "can't find the default branch: no origin/HEAD, main or master".into())
+}
+
+#[cfg(test)]
+mod branch_tests {
+    pub use crate::repo::RepositoryExtension;
+    use crate::test_util::repo_init;
+    pub use crate::test_util::RepositoryTestExtensions;
+    use git2::BranchType;
+
+    #[test]
+    fn repository_with_main_branch() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec!["initial commit"]));
+
+        // When
+        let result = repository.default_branch().unwrap();
+
+        // Then
+        assert_eq!(result, "main");
+    }
+
+    #[test]
+    fn repository_with_master_branch() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec!["initial commit"]));
+        repository
+            .find_branch("main", BranchType::Local)
+            .unwrap()
+            .rename("master", false)
+            .unwrap();
+
+        // When
+        let result = repository.default_branch().unwrap();
+
+        // Then
+        assert_eq!(result, "master");
+    }
+
+    #[test]
+    fn repository_with_remote_head() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec!["initial commit"]));
+        let commit = repository.find_commit_by_message("initial commit").unwrap();
+        repository
+            .reference("refs/remotes/origin/trunk", commit.id(), false, "")
+            .unwrap();
+        repository
+            .reference_symbolic(
+                "refs/remotes/origin/HEAD",
+                "refs/remotes/origin/trunk",
+                false,
+                "",
+            )
+            .unwrap();
+
+        // When
+        let result = repository.default_branch().unwrap();
+
+        // Then
+        assert_eq!(result, "origin/trunk");
+    }
+
+    #[test]
+    fn repository_without_default_branch() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec!["initial commit"]));
+        repository
+            .find_branch("main", BranchType::Local)
+            .unwrap()
+            .rename("feature", false)
+            .unwrap();
+
+        // When
+        let result = repository.default_branch();
+
+        // Then
+        assert!(result.is_err(), "Expected an error, but got Ok");
+    }
+}
@@ -1,42 +1,222 @@
-use crate::repo::ConventionalCommit;
+use crate::repo::error_context::ErrorContext;
+use crate::repo::{ConventionalCommit, VersionScheme, DEFAULT_TAG_PREFIXES};
 use git2::Oid;
-use git2::Repository;
+use git2::{Commit, ErrorCode, Repository, RevparseMode};
 use std::error::Error;
+use std::path::{Path, PathBuf};
+
+/// Options that control which commits are fetched from a repository.
+#[derive(Debug, Clone)]
+pub struct FetchOptions {
+    /// Name of the branch, tag or revision to walk from. `HEAD` is used when `None`.
+    pub reference: Option<String>,
+    /// Only fetch the commits that change files under this path, relative to the
+    /// repository root. All commits are fetched when `None`.
+    pub path: Option<PathBuf>,
+    /// Include the commit where the fetching stops, e.g. the commit of the latest
+    /// version tag. The stop commit is excluded when `false`.
+    pub inclusive: bool,
+    /// Prefixes of the version tags that mark the commit where the fetching stops.
+    /// [`DEFAULT_TAG_PREFIXES`] are used by default.
+    pub tag_prefixes: Vec<String>,
+    /// Stop fetching after walking this many commits, even if the stop commit is not
+    /// reached. The commits are not limited when `None`.
+    pub max_commits: Option<usize>,
+    /// Stop at the latest version tag reachable from HEAD, instead of the highest version
+    /// tag of the repository, e.g. for hotfix branches.
+    pub reachable_tags_only: bool,
+    /// Only follow the first parent of merge commits, so the commits of merged branches
+    /// are skipped and only the merge commits represent them. Merge commits are analyzed
+    /// like any other commit, so they only count if their message has an intention.
+    pub first_parent: bool,
+    /// Versioning scheme of the version tags that mark the commit where the fetching stops.
+    /// Only used with the latest version tag, not with [`reachable_tags_only`].
+    ///
+    /// [`reachable_tags_only`]: FetchOptions::reachable_tags_only
+    pub scheme: VersionScheme,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        Self {
+            reference: None,
+            path: None,
+            inclusive: false,
+            tag_prefixes: DEFAULT_TAG_PREFIXES.map(String::from).to_vec(),
+            max_commits: None,
+            reachable_tags_only: false,
+            first_parent: false,
+            scheme: VersionScheme::SemVer,
+        }
+    }
+}
 
 pub fn fetch_commits_until(
     repository: &Repository,
     stop_oid: Oid,
+    options: &FetchOptions,
 ) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
-    general_fetch_commits_until(repository, Some(stop_oid))
+    general_fetch_commits_until(repository, Some(stop_oid), None, options)
 }
 
 pub fn fetch_all_commits(
     repository: &Repository,
+    options: &FetchOptions,
+) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
+    general_fetch_commits_until(repository, None, None, options)
+}
+
+/// Fetch the commits of a two-dot revspec like `v1.0.0..HEAD`: the commits reachable from the
+/// end of the range, but not from its start. Three-dot ranges are not supported.
+pub fn fetch_commits_in_range(
+    repository: &Repository,
+    range: &str,
+    options: &FetchOptions,
 ) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
-    general_fetch_commits_until(repository, None)
+    let revspec = repository
+        .revparse(range)
+        .map_err(|error| format!("can't resolve range '{range}': {error}"))?;
+    if revspec.mode().contains(RevparseMode::MERGE_BASE) {
+        return Err(format!(
+            "three-dot range '{range}' is not supported, use a two-dot range like 'v1.0.0..HEAD'"
+        )
+        .into());
+    }
+    let (Some(from), Some(to)) = (revspec.from(), revspec.to()) else {
+        return Err(
+            format!("'{range}' is not a range, use a two-dot range like 'v1.0.0..HEAD'").into(),
+        );
+    };
+    let from = from
+        .peel_to_commit()
+        .with_context(|| format!("failed to find the start commit of range '{range}'"))?;
+    let to = to
+        .peel_to_commit()
+        .with_context(|| format!("failed to find the end commit of range '{range}'"))?;
+    let options = FetchOptions {
+        reference: Some(to.id().to_string()),
+        ..options.clone()
+    };
+    general_fetch_commits_until(repository, None, Some(from.id()), &options)
 }
 
+/// Walk the commits of `options.reference` (or HEAD) until `stop_oid`. The commits reachable
+/// from `hidden_oid` are left out of the walk entirely, like the start of a git range.
 fn general_fetch_commits_until(
     repository: &Repository,
     stop_oid: Option<Oid>,
+    hidden_oid: Option<Oid>,
+    options: &FetchOptions,
 ) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
     let mut revwalk = repository.revwalk()?;
-    revwalk.push_head()?;
-
-    Ok(revwalk
-        .filter_map(|object_id| object_id.ok())
-        .take_while(|oid| Some(*oid) != stop_oid)
-        .filter_map(|oid| repository.find_commit(oid).ok())
-        .map(|commit| ConventionalCommit::from_git2_commit(commit))
-        .collect())
+    if options.first_parent {
+        revwalk.simplify_first_parent()?;
+    }
+    match &options.reference {
+        Some(reference) => revwalk.push(resolve_reference(repository, reference)?)?,
+        None => {
+            // HEAD points to a branch without any commits, so there is nothing to fetch.
+            if let Err(error) = repository.head() {
+                if matches!(error.code(), ErrorCode::UnbornBranch | ErrorCode::NotFound) {
+                    return Ok(Vec::new());
+                }
+            }
+            revwalk.push_head()?
+        }
+    }
+    if let Some(hidden_oid) = hidden_oid {
+        revwalk.hide(hidden_oid)?;
+    }
+
+    let mut is_stop_commit_reached = false;
+    let mut commits = revwalk
+        .take_while(|object_id| {
+            if is_stop_commit_reached {
+                return false;
+            }
+            is_stop_commit_reached = matches!(object_id, Ok(oid) if Some(*oid) == stop_oid);
+            !is_stop_commit_reached || options.inclusive
+        })
+        .take(options.max_commits.unwrap_or(usize::MAX))
+        .map(
+            |object_id| -> Result<Option<ConventionalCommit>, Box<dyn Error>> {
+                let oid = object_id.with_context(|| "failed to walk the commits".to_string())?;
+                let commit = repository
+                    .find_commit(oid)
+                    .with_context(|| format!("failed to find commit {oid}"))?;
+                let is_included = match &options.path {
+                    Some(prefix) => touches_path(repository, &commit, prefix)?,
+                    None => true,
+                };
+                Ok(is_included.then(|| ConventionalCommit::from_git2_commit(commit)))
+            },
+        )
+        .filter_map(Result::transpose)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Neighbouring commits with the same author time are ordered by their hash, so the
+    // output doesn't depend on how the walk breaks their ties. Otherwise the walk order is
+    // kept.
+    for same_time_commits in
+        commits.chunk_by_mut(|commit, next_commit| commit.author_time == next_commit.author_time)
+    {
+        same_time_commits.sort_by(|commit, other_commit| commit.hash.cmp(&other_commit.hash));
+    }
+    Ok(commits)
+}
+
+/// Check whether a commit changes any file under the given path compared to its first parent.
+///
+/// The changes of a root commit are compared to an empty tree.
+/// ## Returns
+/// `true` if a file under the path is added, modified or deleted, `false` otherwise.
+pub fn touches_path(
+    repository: &Repository,
+    commit: &Commit,
+    prefix: &Path,
+) -> Result<bool, Box<dyn Error>> {
+    let tree = commit
+        .tree()
+        .with_context(|| format!("failed to find tree of commit {}", commit.id()))?;
+    let parent_tree = match commit.parent(0) {
+        Ok(parent) => Some(
+            parent
+                .tree()
+                .with_context(|| format!("failed to find tree of commit {}", parent.id()))?,
+        ),
+        Err(_) => None,
+    };
+    let diff = repository
+        .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+        .with_context(|| format!("failed to diff commit {}", commit.id()))?;
+
+    Ok(diff.deltas().any(|delta| {
+        [delta.old_file().path(), delta.new_file().path()]
+            .into_iter()
+            .flatten()
+            .any(|path| path.starts_with(prefix))
+    }))
+}
+
+/// Resolve a branch, tag or revision name to the object ID of the commit it points to.
+pub(crate) fn resolve_reference(
+    repository: &Repository,
+    reference: &str,
+) -> Result<Oid, Box<dyn Error>> {
+    let commit = repository
+        .revparse_single(reference)
+        .and_then(|object| object.peel_to_commit())
+        .map_err(|error| format!("can't resolve reference '{reference}': {error}"))?;
+    Ok(commit.id())
 }
 
 #[cfg(test)]
 mod commit_fetcher_tests {
-    use crate::repo::ConventionalCommit;
     pub use crate::repo::RepositoryExtension;
+    use crate::repo::{ConventionalCommit, FetchOptions};
     use crate::test_util::repo_init;
     pub use crate::test_util::RepositoryTestExtensions;
+    use git2::{Signature, Time};
     use std::collections::HashSet;
 
     #[doc(hidden)]
@@ -57,7 +237,9 @@ mod commit_fetcher_tests {
         let (_temp_dir, repository) = repo_init(Some(commit_messages.clone()));
 
         // When
-        let result = repository.fetch_all_commits().unwrap();
+        let result = repository
+            .fetch_all_commits(&FetchOptions::default())
+            .unwrap();
 
         // Then
         assert!(
@@ -75,7 +257,9 @@ mod commit_fetcher_tests {
         let (_temp_dir, repository) = repo_init(Some(commit_messages.clone()));
 
         // When
-        let result = repository.fetch_all_commits().unwrap();
+        let result = repository
+            .fetch_all_commits(&FetchOptions::default())
+            .unwrap();
 
         // Then
         assert!(
@@ -92,10 +276,63 @@ mod commit_fetcher_tests {
         let (_temp_dir, repository) = repo_init(None);
 
         // When
-        let result = repository.fetch_all_commits();
+        let result = repository
+            .fetch_all_commits(&FetchOptions::default())
+            .unwrap();
+
+        // Then
+        assert!(result.is_empty(), "Expected no commits, but got {result:?}")
+    }
+
+    #[test]
+    fn getting_commits_when_head_points_to_unborn_branch() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec!["commit 1", "commit 2"]));
+        repository.set_head("refs/heads/unborn").unwrap();
+
+        // When
+        let result = repository
+            .fetch_all_commits(&FetchOptions::default())
+            .unwrap();
+
+        // Then
+        assert!(result.is_empty(), "Expected no commits, but got {result:?}")
+    }
+
+    #[test]
+    fn getting_commits_when_head_is_corrupted() {
+        // Given
+        let (temp_dir, repository) = repo_init(Some(vec!["commit 1"]));
+        std::fs::write(temp_dir.path().join(".git/HEAD"), "not a reference\n").unwrap();
+
+        // When
+        let result = repository.fetch_all_commits(&FetchOptions::default());
+
+        // Then
+        assert!(result.is_err(), "Expected an error, but got Ok")
+    }
+
+    #[test]
+    fn getting_limited_number_of_commits() {
+        // Given
+        let commit_messages = vec!["commit 1", "commit 2", "commit 3", "commit 4", "commit 5"];
+        let (_temp_dir, repository) = repo_init(Some(commit_messages.clone()));
+        let options = FetchOptions {
+            max_commits: Some(3),
+            ..Default::default()
+        };
+
+        // When
+        let result = repository.fetch_all_commits(&options).unwrap();
 
         // Then
-        assert!(result.is_err(), "Expected and error, but got Ok")
+        let expected_commits = &commit_messages[2..];
+        assert!(
+            compare(&result, expected_commits),
+            "result = {:?}\nexpected messages = {:?}",
+            result,
+            expected_commits
+        )
     }
 
     #[test]
@@ -115,7 +352,10 @@ mod commit_fetcher_tests {
 
         // Then
         let result = repository
-            .fetch_commits_until(version_tagged_commit.unwrap().id())
+            .fetch_commits_until(
+                version_tagged_commit.unwrap().id(),
+                &FetchOptions::default(),
+            )
             .unwrap();
 
         let expected_commits = &commit_messages[3..];
@@ -126,4 +366,357 @@ mod commit_fetcher_tests {
             expected_commits
         )
     }
+
+    #[test]
+    fn getting_commits_until_the_last_version_tag_inclusively() {
+        // Given
+        let commit_messages = vec![
+            ":tada: initial release",
+            ":sparkles: new feature",
+            ":boom: everything is broken",
+            ":memo: add some documentation",
+            ":recycle: refactor the code base",
+            ":rocket: to the moon",
+        ];
+        let (_temp_dir, repository) = repo_init(Some(commit_messages.clone()));
+        let version_tagged_commit =
+            repository.find_commit_by_message(":boom: everything is broken");
+        let options = FetchOptions {
+            inclusive: true,
+            ..Default::default()
+        };
+
+        // When
+        let result = repository
+            .fetch_commits_until(version_tagged_commit.unwrap().id(), &options)
+            .unwrap();
+
+        // Then
+        let expected_commits = &commit_messages[2..];
+        assert!(
+            compare(&result, expected_commits),
+            "result = {:?}\nexpected messages = {:?}",
+            result,
+            expected_commits
+        )
+    }
+
+    #[test]
+    fn getting_commits_until_head() {
+        // Given
+        let commit_messages = vec![":tada: initial release", ":sparkles: new feature"];
+        let (_temp_dir, repository) = repo_init(Some(commit_messages));
+        let head = repository.head().unwrap().peel_to_commit().unwrap();
+
+        // When
+        let result = repository
+            .fetch_commits_until(head.id(), &FetchOptions::default())
+            .unwrap();
+
+        // Then
+        assert!(result.is_empty(), "Expected no commits, but got {result:?}")
+    }
+
+    #[test]
+    fn getting_commits_from_another_branch() {
+        // Given
+        let commit_messages = vec!["commit 1", "commit 2"];
+        let (_temp_dir, repository) = repo_init(Some(commit_messages.clone()));
+        let branch_head = repository.find_commit_by_message("commit 2").unwrap();
+        repository.branch("release/x", &branch_head, false).unwrap();
+        repository.add_commit("commit 3");
+        let options = FetchOptions {
+            reference: Some("release/x".to_string()),
+            ..Default::default()
+        };
+
+        // When
+        let result = repository.fetch_all_commits(&options).unwrap();
+
+        // Then
+        assert!(
+            compare(&result, &commit_messages),
+            "result = {:?}\nexpected messages = {:?}",
+            result,
+            commit_messages
+        )
+    }
+
+    #[test]
+    fn getting_commits_in_range() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec!["commit 1", "commit 2"]));
+        repository.add_tag(
+            repository.find_commit_by_message("commit 1").unwrap(),
+            "v1.0.0",
+        );
+        repository.add_commit("commit 3");
+
+        // When
+        let result = repository
+            .fetch_commits_in_range("v1.0.0..HEAD~1", &FetchOptions::default())
+            .unwrap();
+
+        // Then
+        assert!(
+            compare(&result, &["commit 2"]),
+            "result = {result:?}\nexpected messages = [\"commit 2\"]"
+        )
+    }
+
+    #[test]
+    fn getting_commits_in_range_with_merged_older_branch() {
+        // Given
+        let (_temp_dir, repository) = repo_init(None);
+        let tree_id = repository.index().unwrap().write_tree().unwrap();
+        let tree = repository.find_tree(tree_id).unwrap();
+        let commit_at = |time: i64, message: &str, parents: &[&git2::Commit]| {
+            let signature = Signature::new("name", "email", &Time::new(time, 0)).unwrap();
+            let oid = repository
+                .commit(None, &signature, &signature, message, &tree, parents)
+                .unwrap();
+            repository.find_commit(oid).unwrap()
+        };
+        let initial_commit = commit_at(1_700_000_000, ":tada: initial commit", &[]);
+        let side_commit = commit_at(1_700_000_100, ":sparkles: add feature", &[&initial_commit]);
+        let release_commit = commit_at(1_700_000_200, ":bug: fix bug", &[&initial_commit]);
+        let merge_commit = commit_at(
+            1_700_000_300,
+            "merge branch feature",
+            &[&release_commit, &side_commit],
+        );
+        repository
+            .reference("refs/heads/main", merge_commit.id(), true, "merge")
+            .unwrap();
+        repository.set_head("refs/heads/main").unwrap();
+        repository.add_tag(release_commit, "v1.0.0");
+
+        // When
+        let result = repository
+            .fetch_commits_in_range("v1.0.0..HEAD", &FetchOptions::default())
+            .unwrap();
+
+        // Then
+        let expected_commits = [":sparkles: add feature", "merge branch feature"];
+        assert!(
+            compare(&result, &expected_commits),
+            "result = {result:?}\nexpected messages = {expected_commits:?}"
+        )
+    }
+
+    #[test]
+    fn getting_commits_in_three_dot_range() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec!["commit 1", "commit 2"]));
+
+        // When
+        let result = repository.fetch_commits_in_range("HEAD~1...HEAD", &FetchOptions::default());
+
+        // Then
+        let error = result.expect_err("Expected an error, but got Ok");
+        assert!(
+            error.to_string().contains("three-dot"),
+            "error message doesn't explain the range: {error}"
+        )
+    }
+
+    #[test]
+    fn getting_commits_from_missing_branch() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec!["commit 1"]));
+        let options = FetchOptions {
+            reference: Some("does-not-exist".to_string()),
+            ..Default::default()
+        };
+
+        // When
+        let result = repository.fetch_all_commits(&options);
+
+        // Then
+        let error = result.expect_err("Expected an error, but got Ok");
+        assert!(
+            error.to_string().contains("does-not-exist"),
+            "error message doesn't name the reference: {error}"
+        )
+    }
+
+    #[test]
+    fn getting_commits_touching_path() {
+        // Given
+        let (_temp_dir, repository) = repo_init(None);
+        repository.add_commit_with_file(":sparkles: add foo", "crates/foo/lib.rs");
+        repository.add_commit_with_file(":bug: fix bar", "crates/bar/lib.rs");
+        repository.add_commit_with_file(":recycle: refactor foo", "crates/foo/lib.rs");
+        repository.add_commit_with_file(":memo: add foobar docs", "crates/foobar/README.md");
+        let options = FetchOptions {
+            path: Some("crates/foo".into()),
+            ..Default::default()
+        };
+
+        // When
+        let result = repository.fetch_all_commits(&options).unwrap();
+
+        // Then
+        let expected_commits = [":sparkles: add foo", ":recycle: refactor foo"];
+        assert!(
+            compare(&result, &expected_commits),
+            "result = {:?}\nexpected messages = {:?}",
+            result,
+            expected_commits
+        )
+    }
+
+    #[test]
+    fn ordering_commits_with_same_timestamp() {
+        // Given
+        let (_temp_dir, repository) = repo_init(None);
+        let signature = Signature::new("name", "email", &Time::new(1_700_000_000, 0)).unwrap();
+        let tree_id = repository.index().unwrap().write_tree().unwrap();
+        let tree = repository.find_tree(tree_id).unwrap();
+        for message in [
+            ":tada: initial commit",
+            ":sparkles: add foo",
+            ":bug: fix foo",
+        ] {
+            let parent = repository
+                .head()
+                .ok()
+                .and_then(|head| head.peel_to_commit().ok());
+            let parents: Vec<_> = parent.iter().collect();
+            repository
+                .commit(
+                    Some("HEAD"),
+                    &signature,
+                    &signature,
+                    message,
+                    &tree,
+                    &parents,
+                )
+                .unwrap();
+        }
+
+        // When
+        let first_result = repository
+            .fetch_all_commits(&FetchOptions::default())
+            .unwrap();
+        let second_result = repository
+            .fetch_all_commits(&FetchOptions::default())
+            .unwrap();
+
+        // Then
+        let hashes: Vec<_> = first_result.iter().map(|commit| &commit.hash).collect();
+        let mut sorted_hashes = hashes.clone();
+        sorted_hashes.sort();
+        assert_eq!(hashes, sorted_hashes);
+        assert_eq!(first_result, second_result);
+    }
+
+    #[test]
+    fn keeping_walk_order_of_commits_with_different_timestamps() {
+        // Given
+        let (_temp_dir, repository) = repo_init(None);
+        let tree_id = repository.index().unwrap().write_tree().unwrap();
+        let tree = repository.find_tree(tree_id).unwrap();
+        // Rebased commits keep their author time, so it doesn't follow the history.
+        for (time, message) in [
+            (1_700_000_300, ":tada: initial commit"),
+            (1_700_000_100, ":sparkles: add feature"),
+            (1_700_000_200, ":bug: fix bug"),
+        ] {
+            let signature = Signature::new("name", "email", &Time::new(time, 0)).unwrap();
+            let parent = repository
+                .head()
+                .ok()
+                .and_then(|head| head.peel_to_commit().ok());
+            let parents: Vec<_> = parent.iter().collect();
+            repository
+                .commit(
+                    Some("HEAD"),
+                    &signature,
+                    &signature,
+                    message,
+                    &tree,
+                    &parents,
+                )
+                .unwrap();
+        }
+
+        // When
+        let result = repository
+            .fetch_all_commits(&FetchOptions::default())
+            .unwrap();
+
+        // Then
+        let messages: Vec<_> = result.iter().map(|commit| commit.message()).collect();
+        assert_eq!(
+            messages,
+            [
+                ":bug: fix bug",
+                ":sparkles: add feature",
+                ":tada: initial commit"
+            ]
+        );
+    }
+
+    #[test]
+    fn getting_commits_of_first_parents() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+        let initial_commit = repository
+            .find_commit_by_message(":tada: initial commit")
+            .unwrap();
+        repository
+            .branch("feature", &initial_commit, false)
+            .unwrap();
+        repository.set_head("refs/heads/feature").unwrap();
+        repository.add_commit(":sparkles: add feature");
+        let feature_head = repository
+            .find_commit_by_message(":sparkles: add feature")
+            .unwrap();
+        repository.set_head("refs/heads/main").unwrap();
+        repository.add_commit(":bug: fix bug");
+        let main_head = repository.find_commit_by_message(":bug: fix bug").unwrap();
+        let signature = repository.signature().unwrap();
+        repository
+            .commit(
+                Some("HEAD"),
+                &signature,
+                &signature,
+                "merge branch feature",
+                &main_head.tree().unwrap(),
+                &[&main_head, &feature_head],
+            )
+            .unwrap();
+        let options = FetchOptions {
+            first_parent: true,
+            ..Default::default()
+        };
+
+        // When
+        let all_commits = repository
+            .fetch_all_commits(&FetchOptions::default())
+            .unwrap();
+        let first_parent_commits = repository.fetch_all_commits(&options).unwrap();
+
+        // Then
+        let expected_all_commits = [
+            ":tada: initial commit",
+            ":sparkles: add feature",
+            ":bug: fix bug",
+            "merge branch feature",
+        ];
+        let expected_first_parent_commits = [
+            ":tada: initial commit",
+            ":bug: fix bug",
+            "merge branch feature",
+        ];
+        assert!(
+            compare(&all_commits, &expected_all_commits),
+            "result = {all_commits:?}\nexpected messages = {expected_all_commits:?}"
+        );
+        assert!(
+            compare(&first_parent_commits, &expected_first_parent_commits),
+            "result = {first_parent_commits:?}\nexpected messages = {expected_first_parent_commits:?}"
+        );
+    }
 }
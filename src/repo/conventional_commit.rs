@@ -1,44 +1,224 @@
+use crate::intention::{find_gitmoji, Gitmoji};
+use chrono::{DateTime, FixedOffset};
+use regex::Regex;
 use std::fmt::Display;
 
+/// Default number of characters shown from the commit hash.
+pub const SHORT_HASH_LEN: usize = 7;
+
 /// A structure to represent a git commit.
 ///
 /// Can be created with [`from_git2_commit`] method
-#[derive(Clone, Debug, PartialEq, Hash, Eq)]
+#[derive(Clone, Debug, Default, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, schemars::JsonSchema))]
 pub struct ConventionalCommit {
     pub message: String,
     pub hash: String,
+    pub author_name: String,
+    pub author_email: String,
+    /// Time of the commit by its author, in seconds since the Unix epoch. It's `0` when
+    /// the time is unknown, e.g. for commits read from messages.
+    pub author_time: i64,
+    /// Timezone offset of the author time, in minutes east of UTC.
+    pub author_offset: i32,
 }
 
 impl ConventionalCommit {
     /// Create [`Commit`] from [`git2::Commit`] object.
     ///
+    /// Windows line endings (`\r\n`) of the message are normalized to `\n`. Messages that
+    /// aren't valid UTF-8, e.g. in legacy history, are decoded lossily with replacement
+    /// characters.
+    ///
     /// [`Commit`]: ConventionalCommit
     /// ['git2::Commit`]: git2::Commit
     pub fn from_git2_commit(commit: git2::Commit) -> Self {
+        let author = commit.author();
         Self {
-            message: commit.message().unwrap().to_string(),
+            message: String::from_utf8_lossy(commit.message_bytes()).replace("\r\n", "\n"),
             hash: commit.id().to_string(),
+            author_name: author.name().unwrap_or_default().to_string(),
+            author_email: author.email().unwrap_or_default().to_string(),
+            author_time: author.when().seconds(),
+            author_offset: author.when().offset_minutes(),
+        }
+    }
+
+    /// Return the author time of the commit in the timezone of the author.
+    ///
+    /// ## Returns
+    ///
+    /// The date and time, or `None` if the time is unknown or out of range.
+    pub fn author_date(&self) -> Option<DateTime<FixedOffset>> {
+        if self.author_time == 0 {
+            return None;
         }
+        let offset = FixedOffset::east_opt(self.author_offset.checked_mul(60)?)?;
+        Some(DateTime::from_timestamp(self.author_time, 0)?.with_timezone(&offset))
     }
 
     /// Return a reference to the `message` attribute
     pub fn message(&self) -> &str {
         &self.message
     }
+
+    /// Return the subject of the commit, which is the first line of its message.
+    ///
+    /// The body of the message is left out, like GitHub does when listing commits. The
+    /// intention of the commit is kept in the subject.
+    pub fn subject(&self) -> &str {
+        self.message.lines().next().unwrap_or_default().trim_end()
+    }
+
+    /// Return the body of the commit, which is everything after the first blank line of its
+    /// message, e.g. for reading the footers of the commit.
+    ///
+    /// ## Returns
+    ///
+    /// The body without its trailing newlines, or an empty string if the message has no
+    /// body.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::ConventionalCommit;
+    ///
+    /// let commit = ConventionalCommit {
+    ///     message: ":bug: fix the parser\n\nCloses #42\n".to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(commit.body(), "Closes #42");
+    /// ```
+    pub fn body(&self) -> &str {
+        self.message
+            .split_once("\n\n")
+            .map_or("", |(_, body)| body.trim_start_matches('\n').trim_end())
+    }
+
+    /// Return the built-in gitmoji that is the intention of the commit, e.g. for showing
+    /// the emoji of the commit.
+    ///
+    /// When the message has multiple gitmojis, the one appearing first is returned.
+    ///
+    /// ## Returns
+    ///
+    /// The shortcode and the emoji of the gitmoji, or `None` if the commit has no known
+    /// intention.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::ConventionalCommit;
+    ///
+    /// let commit = ConventionalCommit {
+    ///     message: ":sparkles: add feature".to_string(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let (shortcode, emoji) = commit.intention().unwrap();
+    /// assert_eq!(shortcode, ":sparkles:");
+    /// assert_eq!(emoji, "✨");
+    /// ```
+    pub fn intention(&self) -> Option<Gitmoji> {
+        find_gitmoji(&self.message)
+    }
+
+    /// Return the name and the email address of the author of the commit.
+    ///
+    /// Both are empty strings if they are missing from the commit.
+    pub fn author(&self) -> (&str, &str) {
+        (&self.author_name, &self.author_email)
+    }
+
+    /// Return the scope of the commit.
+    ///
+    /// The scope is the parenthesized part following the intention of the commit,
+    /// e.g. `parser` in `✨ (parser): add new syntax`.
+    ///
+    /// ## Returns
+    ///
+    /// The scope of the commit, or an empty string if the commit has no scope.
+    pub fn scope(&self) -> &str {
+        let scope_regex = Regex::new(r"^\S+?\s*\(([^()\s]+)\)").unwrap();
+        scope_regex
+            .captures(self.message.lines().next().unwrap_or_default())
+            .and_then(|captures| captures.get(1))
+            .map_or("", |scope| scope.as_str())
+    }
+
+    /// Return the abbreviated hash of the commit, like the one shown by `git log --oneline`.
+    ///
+    /// If the hash is shorter than [`SHORT_HASH_LEN`] then the full hash is returned.
+    pub fn short_hash(&self) -> &str {
+        self.abbreviated_hash(SHORT_HASH_LEN)
+    }
+
+    /// Return the namespace of the hash, e.g. the path of the submodule that the commit
+    /// comes from.
+    ///
+    /// Hashes are namespaced as `<namespace>:<hash>`. The namespace is `""` for the
+    /// commits of the analyzed repository itself.
+    pub fn namespace(&self) -> &str {
+        self.hash
+            .rsplit_once(':')
+            .map_or("", |(namespace, _)| namespace)
+    }
+
+    /// Return the hash of the commit without its namespace.
+    pub fn object_hash(&self) -> &str {
+        self.hash
+            .rsplit_once(':')
+            .map_or(&self.hash, |(_, hash)| hash)
+    }
+
+    /// Abbreviate the hash to `hash_len` characters, keeping its namespace.
+    fn abbreviated_hash(&self, hash_len: usize) -> &str {
+        let namespace_len = self.hash.len() - self.object_hash().len();
+        self.hash
+            .get(0..namespace_len.saturating_add(hash_len))
+            .unwrap_or(&self.hash)
+    }
+
+    /// Format the commit as `<subject> - <abbreviated hash>`.
+    ///
+    /// Only the [`subject`] of the message is shown, the full message stays available in
+    /// the `message` attribute.
+    ///
+    /// The hash is abbreviated to `hash_len` characters. If `hash_len` exceeds the length of
+    /// the hash then the full hash is shown.
+    ///
+    /// [`subject`]: ConventionalCommit::subject
+    pub fn format_commit(&self, hash_len: usize) -> String {
+        format!("{} - {}", self.subject(), self.abbreviated_hash(hash_len))
+    }
+
+    /// Return the hash of the commit that this commit reverts.
+    ///
+    /// The hash is taken from the `This reverts commit <hash>` line that git writes into
+    /// the message of revert commits.
+    ///
+    /// ## Returns
+    ///
+    /// The (possibly abbreviated) hash if the commit is a revert commit, `None` otherwise.
+    pub fn reverted_hash(&self) -> Option<&str> {
+        let revert_regex = Regex::new(r"This reverts commit ([0-9a-f]{7,40})").unwrap();
+        revert_regex
+            .captures(&self.message)
+            .and_then(|captures| captures.get(1))
+            .map(|hash| hash.as_str())
+    }
 }
 
 impl Display for ConventionalCommit {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let short_hash = self
-            .hash
-            .get(0..7)
-            .unwrap_or("Error: can't show short hash");
-        write!(f, "{} - {}", self.message.trim_end(), short_hash)
+        write!(f, "{}", self.format_commit(SHORT_HASH_LEN))
     }
 }
 
 #[cfg(test)]
 mod conventional_commit_tests {
+    use crate::intention::{Category, Classify, IntentionMap};
     use crate::repo::ConventionalCommit;
     use crate::test_util::{repo_init, RepositoryTestExtensions};
 
@@ -56,10 +236,27 @@ mod conventional_commit_tests {
         let expected_result = ConventionalCommit {
             message: git2_commit.message().unwrap().to_string(),
             hash: git2_commit.id().to_string(),
+            author_name: "name".to_string(),
+            author_email: "email".to_string(),
+            author_time: git2_commit.author().when().seconds(),
+            author_offset: git2_commit.author().when().offset_minutes(),
         };
         assert_eq!(result, expected_result)
     }
 
+    #[test]
+    fn author_of_git2_commit() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec!["initial commit"]));
+        let git2_commit = repository.find_commit_by_message("initial commit").unwrap();
+
+        // When
+        let result = ConventionalCommit::from_git2_commit(git2_commit);
+
+        // Then
+        assert_eq!(result.author(), ("name", "email"))
+    }
+
     #[test]
     fn display_formatting() {
         // Given
@@ -81,4 +278,307 @@ mod conventional_commit_tests {
             )
         )
     }
+
+    #[test]
+    fn intention_written_as_emoji() {
+        // Given
+        let commit = ConventionalCommit {
+            message: "🐛 fix the :sparkles: parser".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.intention();
+
+        // Then
+        assert_eq!(result, Some((":bug:", "🐛")));
+    }
+
+    #[test]
+    fn commit_without_intention() {
+        // Given
+        let commit = ConventionalCommit {
+            message: "fix the parser".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.intention();
+
+        // Then
+        assert!(result.is_none(), "Expected None, but got {result:?}");
+    }
+
+    #[test]
+    fn subject_of_multi_line_commit() {
+        // Given
+        let commit = ConventionalCommit {
+            message: ":sparkles: add feature\n\nThe feature is described\nin the body.\n"
+                .to_string(),
+            hash: "3c8e19bf7fe7ba3dd6f0489374a5200fad124377".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.subject();
+
+        // Then
+        assert_eq!(result, ":sparkles: add feature");
+        assert_eq!(commit.to_string(), ":sparkles: add feature - 3c8e19b");
+        assert!(commit.message().contains("in the body."));
+    }
+
+    #[test]
+    fn creating_from_commit_with_non_utf8_message() {
+        // Given
+        let (_temp_dir, repository) = repo_init(None);
+        let tree_oid = repository.index().unwrap().write_tree().unwrap();
+        let mut content = format!(
+            "tree {tree_oid}\nauthor name <email> 0 +0000\ncommitter name <email> 0 +0000\n\n"
+        )
+        .into_bytes();
+        content.extend_from_slice(b":bug: fix caf\xe9 parser\n");
+        let oid = repository
+            .odb()
+            .unwrap()
+            .write(git2::ObjectType::Commit, &content)
+            .unwrap();
+        let git2_commit = repository.find_commit(oid).unwrap();
+
+        // When
+        let result = ConventionalCommit::from_git2_commit(git2_commit.clone());
+
+        // Then
+        assert_eq!(result.message, ":bug: fix caf\u{FFFD} parser\n");
+        assert_eq!(
+            git2_commit.category(&IntentionMap::default()),
+            Some(Category::Patch)
+        );
+    }
+
+    #[test]
+    fn body_of_multi_paragraph_commit() {
+        // Given
+        let commit = ConventionalCommit {
+            message: ":boom: remove the old API\n\nThe old API is removed.\n\nBREAKING CHANGE: use the new API\n"
+                .to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.body();
+
+        // Then
+        assert_eq!(
+            result,
+            "The old API is removed.\n\nBREAKING CHANGE: use the new API"
+        );
+    }
+
+    #[test]
+    fn body_of_single_line_commit() {
+        // Given
+        let commit = ConventionalCommit {
+            message: "🐛 fix the parser\n".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.body();
+
+        // Then
+        assert_eq!(result, "");
+    }
+
+    #[test]
+    fn subject_of_single_line_commit() {
+        // Given
+        let commit = ConventionalCommit {
+            message: "🐛 fix the parser\n".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.subject();
+
+        // Then
+        assert_eq!(result, "🐛 fix the parser");
+    }
+
+    #[test]
+    fn creating_from_commit_with_windows_line_endings() {
+        // Given
+        let commit_message = ":sparkles:(parser) add feature\r\n\r\nlonger description\r\n";
+        let (_temp_dir, repository) = repo_init(Some(vec![commit_message]));
+        let commit = repository.find_commit_by_message(commit_message).unwrap();
+
+        // When
+        let result = ConventionalCommit::from_git2_commit(commit);
+
+        // Then
+        assert!(
+            !result.message.contains('\r'),
+            "message has stray carriage return: {:?}",
+            result.message
+        );
+        assert_eq!(result.scope(), "parser");
+        assert_eq!(
+            result.category(&IntentionMap::default()),
+            Some(Category::Minor)
+        );
+    }
+
+    #[test]
+    fn short_hash_is_the_first_seven_characters() {
+        // Given
+        let commit = ConventionalCommit {
+            message: "initial commit".to_string(),
+            hash: "3c8e19bf7fe7ba3dd6f0489374a5200fad124377".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.short_hash();
+
+        // Then
+        assert_eq!(result, "3c8e19b")
+    }
+
+    #[test]
+    fn short_hash_of_too_short_hash_is_the_full_hash() {
+        // Given
+        let commit = ConventionalCommit {
+            message: "initial commit".to_string(),
+            hash: "3c8e".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let short_hash = commit.short_hash();
+        let displayed = commit.to_string();
+
+        // Then
+        assert_eq!(short_hash, "3c8e");
+        assert_eq!(displayed, "initial commit - 3c8e");
+    }
+
+    #[test]
+    fn short_hash_keeps_the_namespace() {
+        // Given
+        let commit = ConventionalCommit {
+            message: "initial commit".to_string(),
+            hash: "libs/core:3c8e19bf7fe7ba3dd6f0489374a5200fad124377".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.short_hash();
+
+        // Then
+        assert_eq!(result, "libs/core:3c8e19b");
+        assert_eq!(commit.namespace(), "libs/core");
+        assert_eq!(
+            commit.object_hash(),
+            "3c8e19bf7fe7ba3dd6f0489374a5200fad124377"
+        );
+    }
+
+    #[test]
+    fn formatting_with_hash_length() {
+        // Given
+        let commit = ConventionalCommit {
+            message: "initial commit\n".to_string(),
+            hash: "3c8e19bf7fe7ba3dd6f0489374a5200fad124377".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let results = [4, 7, 100].map(|hash_len| commit.format_commit(hash_len));
+
+        // Then
+        assert_eq!(
+            results,
+            [
+                "initial commit - 3c8e".to_string(),
+                "initial commit - 3c8e19b".to_string(),
+                "initial commit - 3c8e19bf7fe7ba3dd6f0489374a5200fad124377".to_string(),
+            ]
+        )
+    }
+
+    #[test]
+    fn formatting_namespaced_commit_with_maximal_hash_length() {
+        // Given
+        let commit = ConventionalCommit {
+            message: "initial commit\n".to_string(),
+            hash: "core:3c8e19bf7fe7ba3dd6f0489374a5200fad124377".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.format_commit(usize::MAX);
+
+        // Then
+        assert_eq!(
+            result,
+            "initial commit - core:3c8e19bf7fe7ba3dd6f0489374a5200fad124377"
+        )
+    }
+
+    #[test]
+    fn scope_of_commits() {
+        // Given
+        let messages = [
+            ("✨ (parser): add new syntax", "parser"),
+            (":bug:(cli) fix argument parsing", "cli"),
+            (":memo: add documentation (finally)", ""),
+            (":recycle: refactor code", ""),
+        ];
+
+        for (message, expected_scope) in messages {
+            let commit = ConventionalCommit {
+                message: message.to_string(),
+                hash: "".to_string(),
+                ..Default::default()
+            };
+
+            // When
+            let result = commit.scope();
+
+            // Then
+            assert_eq!(result, expected_scope, "wrong scope for '{message}'")
+        }
+    }
+
+    #[test]
+    fn reverted_hash_of_revert_commit() {
+        // Given
+        let commit = ConventionalCommit {
+            message: "⏪️ Revert \"✨ add feature\"\n\nThis reverts commit 3c8e19bf7fe7ba3dd6f0489374a5200fad124377.\n".to_string(),
+            hash: "8e50d697e09574e94ea0af1136f3ef73ca3519f0".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.reverted_hash();
+
+        // Then
+        assert_eq!(result, Some("3c8e19bf7fe7ba3dd6f0489374a5200fad124377"))
+    }
+
+    #[test]
+    fn reverted_hash_of_normal_commit() {
+        // Given
+        let commit = ConventionalCommit {
+            message: "✨ add feature".to_string(),
+            hash: "3c8e19bf7fe7ba3dd6f0489374a5200fad124377".to_string(),
+            ..Default::default()
+        };
+
+        // When
+        let result = commit.reverted_hash();
+
+        // Then
+        assert!(result.is_none(), "Expected None, but got Some")
+    }
 }
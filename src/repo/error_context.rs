@@ -0,0 +1,45 @@
+use std::error::Error;
+
+/// Extension for git2 results to add context about the failed operation to the error.
+pub trait ErrorContext<T> {
+    /// Prefix the error message with the description returned by `context`.
+    ///
+    /// ## Returns
+    ///
+    /// The value of the result or the error with the context.
+    fn with_context(self, context: impl FnOnce() -> String) -> Result<T, Box<dyn Error>>;
+}
+
+impl<T> ErrorContext<T> for Result<T, git2::Error> {
+    fn with_context(self, context: impl FnOnce() -> String) -> Result<T, Box<dyn Error>> {
+        self.map_err(|error| format!("{}: {}", context(), error.message()).into())
+    }
+}
+
+#[cfg(test)]
+mod error_context_tests {
+    use crate::repo::error_context::ErrorContext;
+    use git2::{Oid, Repository};
+
+    #[test]
+    fn error_message_contains_context() {
+        // Given
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let repository = Repository::init(temp_dir.path()).unwrap();
+        let oid = Oid::from_str("3c8e19bf7fe7ba3dd6f0489374a5200fad124377").unwrap();
+
+        // When
+        let result = repository
+            .find_commit(oid)
+            .with_context(|| format!("failed to find commit {oid}"));
+
+        // Then
+        let error = result.expect_err("Expected an error, but got Ok");
+        assert!(
+            error
+                .to_string()
+                .starts_with("failed to find commit 3c8e19bf7fe7ba3dd6f0489374a5200fad124377: "),
+            "error message doesn't have the context: {error}"
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use git2::ErrorCode;
+use std::thread;
+use std::time::Duration;
+
+/// Number of times a git operation is retried when the repository is locked.
+pub const LOCK_RETRIES: u32 = 3;
+
+/// Delay before the first retry, doubled before each further retry.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(20);
+
+/// Run a mutating git operation, retrying it while the repository is locked.
+///
+/// Other git operations running at the same time, e.g. in CI, can hold the lock of the
+/// index or of a reference for a short time. The operation is retried up to
+/// [`LOCK_RETRIES`] times with an increasing delay if it fails with
+/// [`ErrorCode::Locked`]; other errors are returned immediately.
+/// ## Returns
+/// The result of the first attempt that is not a lock error, or the last lock error.
+pub fn retry_on_lock<T>(
+    mut operation: impl FnMut() -> Result<T, git2::Error>,
+) -> Result<T, git2::Error> {
+    let mut backoff = INITIAL_BACKOFF;
+    for _ in 0..LOCK_RETRIES {
+        match operation() {
+            Err(error) if error.code() == ErrorCode::Locked => {
+                thread::sleep(backoff);
+                backoff *= 2;
+            }
+            result => return result,
+        }
+    }
+    operation()
+}
+
+#[cfg(test)]
+mod lock_retry_tests {
+    use crate::repo::lock_retry::{retry_on_lock, LOCK_RETRIES};
+    use git2::{ErrorClass, ErrorCode};
+
+    fn locked_operation(failures: u32) -> impl FnMut() -> Result<u32, git2::Error> {
+        let mut attempts = 0;
+        move || {
+            attempts += 1;
+            if attempts <= failures {
+                Err(git2::Error::new(
+                    ErrorCode::Locked,
+                    ErrorClass::Reference,
+                    "failed to lock file",
+                ))
+            } else {
+                Ok(attempts)
+            }
+        }
+    }
+
+    #[test]
+    fn operation_succeeds_after_lock_is_released() {
+        // Given
+        let operation = locked_operation(LOCK_RETRIES);
+
+        // When
+        let result = retry_on_lock(operation);
+
+        // Then
+        assert_eq!(result.unwrap(), LOCK_RETRIES + 1);
+    }
+
+    #[test]
+    fn lock_error_is_returned_after_retries() {
+        // Given
+        let operation = locked_operation(LOCK_RETRIES + 1);
+
+        // When
+        let result = retry_on_lock(operation);
+
+        // Then
+        let error = result.expect_err("Expected an error, but got Ok");
+        assert_eq!(error.code(), ErrorCode::Locked);
+    }
+
+    #[test]
+    fn other_errors_are_not_retried() {
+        // Given
+        let mut attempts = 0;
+
+        // When
+        let result: Result<(), git2::Error> = retry_on_lock(|| {
+            attempts += 1;
+            Err(git2::Error::from_str("object not found"))
+        });
+
+        // Then
+        assert!(result.is_err(), "Expected an error, but got Ok");
+        assert_eq!(attempts, 1);
+    }
+}
@@ -1,34 +1,187 @@
+mod branch;
 mod commit_fetcher;
 mod conventional_commit;
+mod error_context;
+mod lock_retry;
+mod remote;
 mod version_tag;
+mod working_tree;
 
-use crate::repo::commit_fetcher::{fetch_all_commits, fetch_commits_until};
-use crate::repo::version_tag::get_latest_version_tag;
-pub use conventional_commit::ConventionalCommit;
+use crate::repo::branch::default_branch;
+use crate::repo::commit_fetcher::{
+    fetch_all_commits, fetch_commits_in_range, fetch_commits_until, resolve_reference,
+};
+use crate::repo::version_tag::{
+    find_duplicate_version_tags, find_malformed_version_tags, get_all_version_tags,
+    get_all_version_tags_with_scheme, get_latest_reachable_version_tag, get_latest_version_tag,
+    get_latest_version_tag_reachable_from, get_latest_version_tag_with_scheme,
+};
+use crate::repo::working_tree::is_clean;
+pub use commit_fetcher::FetchOptions;
+pub use conventional_commit::{ConventionalCommit, SHORT_HASH_LEN};
 use git2::{Oid, Repository};
+pub use lock_retry::{retry_on_lock, LOCK_RETRIES};
+pub use remote::{normalize_remote_url, remote_url, RemoteHost};
 use std::error::Error;
-pub use version_tag::VersionTag;
+pub use version_tag::{
+    next_calver, DuplicateVersionTag, TagKind, VersionScheme, VersionTag, DEFAULT_TAG_PREFIXES,
+};
 
 pub trait RepositoryExtension {
-    fn fetch_commits_until(&self, stop_oid: Oid)
-        -> Result<Vec<ConventionalCommit>, Box<dyn Error>>;
-    fn fetch_all_commits(&self) -> Result<Vec<ConventionalCommit>, Box<dyn Error>>;
-    fn get_latest_version_tag(&self) -> Result<Option<VersionTag>, Box<dyn Error>>;
+    fn fetch_commits_until(
+        &self,
+        stop_oid: Oid,
+        options: &FetchOptions,
+    ) -> Result<Vec<ConventionalCommit>, Box<dyn Error>>;
+    fn fetch_all_commits(
+        &self,
+        options: &FetchOptions,
+    ) -> Result<Vec<ConventionalCommit>, Box<dyn Error>>;
+    fn fetch_commits_in_range(
+        &self,
+        range: &str,
+        options: &FetchOptions,
+    ) -> Result<Vec<ConventionalCommit>, Box<dyn Error>>;
+    fn get_latest_version_tag(&self) -> Result<Option<VersionTag>, Box<dyn Error>> {
+        let prefixes: Vec<String> = DEFAULT_TAG_PREFIXES.map(String::from).to_vec();
+        self.get_latest_version_tag_with_prefixes(&prefixes)
+    }
+    fn get_latest_version_tag_with_prefixes(
+        &self,
+        prefixes: &[String],
+    ) -> Result<Option<VersionTag>, Box<dyn Error>>;
+    fn latest_version_tag_with_scheme(
+        &self,
+        prefixes: &[String],
+        scheme: VersionScheme,
+    ) -> Result<Option<VersionTag>, Box<dyn Error>>;
+    fn latest_reachable_version_tag(&self) -> Result<Option<VersionTag>, Box<dyn Error>> {
+        let prefixes: Vec<String> = DEFAULT_TAG_PREFIXES.map(String::from).to_vec();
+        self.latest_reachable_version_tag_with_prefixes(&prefixes)
+    }
+    fn latest_reachable_version_tag_with_prefixes(
+        &self,
+        prefixes: &[String],
+    ) -> Result<Option<VersionTag>, Box<dyn Error>>;
+    fn latest_version_tag_reachable_from(
+        &self,
+        reference: &str,
+        prefixes: &[String],
+    ) -> Result<Option<VersionTag>, Box<dyn Error>>;
+    fn all_version_tags(&self) -> Result<Vec<VersionTag>, Box<dyn Error>> {
+        let prefixes: Vec<String> = DEFAULT_TAG_PREFIXES.map(String::from).to_vec();
+        self.all_version_tags_with_prefixes(&prefixes)
+    }
+    fn all_version_tags_with_prefixes(
+        &self,
+        prefixes: &[String],
+    ) -> Result<Vec<VersionTag>, Box<dyn Error>>;
+    fn all_version_tags_with_scheme(
+        &self,
+        prefixes: &[String],
+        scheme: VersionScheme,
+    ) -> Result<Vec<VersionTag>, Box<dyn Error>>;
+    fn find_duplicate_version_tags(
+        &self,
+        prefixes: &[String],
+    ) -> Result<Vec<DuplicateVersionTag>, Box<dyn Error>>;
+    fn find_malformed_version_tags(
+        &self,
+        prefixes: &[String],
+    ) -> Result<Vec<String>, Box<dyn Error>>;
+    fn default_branch(&self) -> Result<String, Box<dyn Error>>;
+    fn is_clean(&self) -> Result<bool, Box<dyn Error>>;
 }
 
 impl RepositoryExtension for Repository {
     fn fetch_commits_until(
         &self,
         stop_oid: Oid,
+        options: &FetchOptions,
+    ) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
+        fetch_commits_until(self, stop_oid, options)
+    }
+
+    fn fetch_all_commits(
+        &self,
+        options: &FetchOptions,
     ) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
-        fetch_commits_until(self, stop_oid)
+        fetch_all_commits(self, options)
     }
 
-    fn fetch_all_commits(&self) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
-        fetch_all_commits(self)
+    fn fetch_commits_in_range(
+        &self,
+        range: &str,
+        options: &FetchOptions,
+    ) -> Result<Vec<ConventionalCommit>, Box<dyn Error>> {
+        fetch_commits_in_range(self, range, options)
     }
 
-    fn get_latest_version_tag(&self) -> Result<Option<VersionTag>, Box<dyn Error>> {
-        get_latest_version_tag(self)
+    fn get_latest_version_tag_with_prefixes(
+        &self,
+        prefixes: &[String],
+    ) -> Result<Option<VersionTag>, Box<dyn Error>> {
+        get_latest_version_tag(self, prefixes)
+    }
+
+    fn latest_version_tag_with_scheme(
+        &self,
+        prefixes: &[String],
+        scheme: VersionScheme,
+    ) -> Result<Option<VersionTag>, Box<dyn Error>> {
+        get_latest_version_tag_with_scheme(self, prefixes, scheme)
+    }
+
+    fn latest_reachable_version_tag_with_prefixes(
+        &self,
+        prefixes: &[String],
+    ) -> Result<Option<VersionTag>, Box<dyn Error>> {
+        get_latest_reachable_version_tag(self, prefixes)
+    }
+
+    fn latest_version_tag_reachable_from(
+        &self,
+        reference: &str,
+        prefixes: &[String],
+    ) -> Result<Option<VersionTag>, Box<dyn Error>> {
+        let tip_oid = resolve_reference(self, reference)?;
+        get_latest_version_tag_reachable_from(self, tip_oid, prefixes)
+    }
+
+    fn all_version_tags_with_prefixes(
+        &self,
+        prefixes: &[String],
+    ) -> Result<Vec<VersionTag>, Box<dyn Error>> {
+        get_all_version_tags(self, prefixes)
+    }
+
+    fn all_version_tags_with_scheme(
+        &self,
+        prefixes: &[String],
+        scheme: VersionScheme,
+    ) -> Result<Vec<VersionTag>, Box<dyn Error>> {
+        get_all_version_tags_with_scheme(self, prefixes, scheme)
+    }
+
+    fn find_duplicate_version_tags(
+        &self,
+        prefixes: &[String],
+    ) -> Result<Vec<DuplicateVersionTag>, Box<dyn Error>> {
+        find_duplicate_version_tags(self, prefixes)
+    }
+
+    fn find_malformed_version_tags(
+        &self,
+        prefixes: &[String],
+    ) -> Result<Vec<String>, Box<dyn Error>> {
+        find_malformed_version_tags(self, prefixes)
+    }
+
+    fn default_branch(&self) -> Result<String, Box<dyn Error>> {
+        default_branch(self)
+    }
+
+    fn is_clean(&self) -> Result<bool, Box<dyn Error>> {
+        is_clean(self)
     }
 }
@@ -0,0 +1,188 @@
+use git2::{ErrorCode, Repository};
+use std::error::Error;
+
+/// Get the web URL of the `origin` remote of the repository.
+///
+/// The URL is normalized with [`normalize_remote_url`], so SSH remotes are converted to
+/// their HTTPS form.
+/// ## Returns
+/// The normalized URL, or `None` if the repository doesn't have an `origin` remote.
+pub fn remote_url(repository: &Repository) -> Result<Option<String>, Box<dyn Error>> {
+    let remote = match repository.find_remote("origin") {
+        Ok(remote) => remote,
+        Err(error) if error.code() == ErrorCode::NotFound => return Ok(None),
+        Err(error) => return Err(error.into()),
+    };
+    Ok(remote.url().map(normalize_remote_url))
+}
+
+/// Convert a remote URL into the URL of the repository's web page.
+///
+/// SSH (`git@host:owner/repo.git`, `ssh://git@host:22/owner/repo.git`) and `git://`
+/// remotes are converted to `https://host/owner/repo`, without the port, which belongs to
+/// SSH or git and not to the web server. The port of `http` and `https` remotes is kept.
+/// User information, the `.git` suffix and trailing slashes are removed.
+pub fn normalize_remote_url(remote_url: &str) -> String {
+    let remote_url = remote_url.trim().trim_end_matches('/');
+    let remote_url = remote_url.strip_suffix(".git").unwrap_or(remote_url);
+
+    let (scheme, location, is_web_remote) = match remote_url.split_once("://") {
+        Some((scheme @ ("http" | "https"), location)) => (scheme, location.to_string(), true),
+        Some((_, location)) => ("https", location.to_string(), false),
+        // scp-like syntax, e.g. `git@github.com:owner/repo`
+        None => ("https", remote_url.replacen(':', "/", 1), false),
+    };
+    let (host, path) = location.split_once('/').unwrap_or((&location, ""));
+    let host = host.rsplit_once('@').map_or(host, |(_, host)| host);
+    let host = if is_web_remote {
+        host
+    } else {
+        host.split_once(':').map_or(host, |(host, _)| host)
+    };
+    format!("{scheme}://{host}/{path}")
+}
+
+/// Enum to represent the hosting service of a remote repository
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteHost {
+    GitHub,
+    GitLab,
+    Bitbucket,
+    /// Any other host, which is assumed to use GitHub-like URLs (e.g. Gitea, Forgejo)
+    SelfHosted,
+}
+
+impl RemoteHost {
+    /// Detect the hosting service from a remote URL.
+    ///
+    /// Hosts starting with `gitlab.` are recognized as self-hosted GitLab instances.
+    pub fn detect(remote_url: &str) -> Self {
+        let normalized_url = normalize_remote_url(remote_url);
+        let host = normalized_url
+            .split_once("://")
+            .and_then(|(_, location)| location.split('/').next())
+            .unwrap_or_default();
+        match host {
+            "github.com" => RemoteHost::GitHub,
+            "bitbucket.org" => RemoteHost::Bitbucket,
+            host if host == "gitlab.com" || host.starts_with("gitlab.") => RemoteHost::GitLab,
+            _ => RemoteHost::SelfHosted,
+        }
+    }
+
+    /// Create the link to a commit on the web page of the remote repository.
+    ///
+    /// ## Returns
+    ///
+    /// The URL of the commit, e.g. `https://gitlab.com/group/repo/-/commit/<hash>`.
+    pub fn commit_url(&self, remote_url: &str, hash: &str) -> String {
+        let repository_url = normalize_remote_url(remote_url);
+        match self {
+            RemoteHost::GitLab => format!("{repository_url}/-/commit/{hash}"),
+            RemoteHost::Bitbucket => format!("{repository_url}/commits/{hash}"),
+            RemoteHost::GitHub | RemoteHost::SelfHosted => {
+                format!("{repository_url}/commit/{hash}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod remote_tests {
+    use crate::repo::remote::{normalize_remote_url, remote_url, RemoteHost};
+    use crate::test_util::repo_init;
+
+    const HASH: &str = "3c8e19bf7fe7ba3dd6f0489374a5200fad124377";
+
+    fn commit_url(remote_url: &str) -> String {
+        RemoteHost::detect(remote_url).commit_url(remote_url, HASH)
+    }
+
+    #[test]
+    fn github_remotes() {
+        let expected_url = format!("https://github.com/owner/repo/commit/{HASH}");
+        assert_eq!(commit_url("git@github.com:owner/repo.git"), expected_url);
+        assert_eq!(
+            commit_url("https://github.com/owner/repo.git"),
+            expected_url
+        );
+    }
+
+    #[test]
+    fn gitlab_remotes() {
+        let expected_url = format!("https://gitlab.com/group/subgroup/repo/-/commit/{HASH}");
+        assert_eq!(
+            commit_url("git@gitlab.com:group/subgroup/repo.git"),
+            expected_url
+        );
+        assert_eq!(
+            commit_url("https://gitlab.com/group/subgroup/repo"),
+            expected_url
+        );
+    }
+
+    #[test]
+    fn bitbucket_remotes() {
+        let expected_url = format!("https://bitbucket.org/owner/repo/commits/{HASH}");
+        assert_eq!(commit_url("git@bitbucket.org:owner/repo.git"), expected_url);
+        assert_eq!(
+            commit_url("https://user@bitbucket.org/owner/repo.git"),
+            expected_url
+        );
+    }
+
+    #[test]
+    fn self_hosted_remotes() {
+        assert_eq!(
+            commit_url("ssh://git@git.example.com:2222/owner/repo.git"),
+            format!("https://git.example.com/owner/repo/commit/{HASH}")
+        );
+        assert_eq!(
+            commit_url("http://git.example.com:3000/owner/repo/"),
+            format!("http://git.example.com:3000/owner/repo/commit/{HASH}")
+        );
+        assert_eq!(
+            commit_url("https://git.example.com:8443/owner/repo.git"),
+            format!("https://git.example.com:8443/owner/repo/commit/{HASH}")
+        );
+        assert_eq!(
+            commit_url("git@gitlab.example.com:group/repo.git"),
+            format!("https://gitlab.example.com/group/repo/-/commit/{HASH}")
+        );
+    }
+
+    #[test]
+    fn normalizing_remote_url() {
+        assert_eq!(
+            normalize_remote_url("git://github.com/owner/repo.git"),
+            "https://github.com/owner/repo"
+        );
+    }
+
+    #[test]
+    fn repository_with_origin_remote() {
+        // Given
+        let (_temp_dir, repository) = repo_init(None);
+        repository
+            .remote("origin", "git@github.com:owner/repo.git")
+            .unwrap();
+
+        // When
+        let result = remote_url(&repository).unwrap();
+
+        // Then
+        assert_eq!(result.as_deref(), Some("https://github.com/owner/repo"));
+    }
+
+    #[test]
+    fn repository_without_origin_remote() {
+        // Given
+        let (_temp_dir, repository) = repo_init(None);
+
+        // When
+        let result = remote_url(&repository).unwrap();
+
+        // Then
+        assert!(result.is_none(), "Expected None, but got {result:?}");
+    }
+}
@@ -1,38 +1,203 @@
-use git2::{Object, ObjectType, Oid, Reference, Repository, Tag};
+use crate::repo::error_context::ErrorContext;
+use git2::{ErrorCode, Object, ObjectType, Oid, Reference, Repository, Tag};
 use regex::Regex;
 use semver::Version;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::error::Error;
 
+/// Prefixes of the version tags that are recognized by default, e.g. `v1.0.0` and `1.0.0`.
+pub const DEFAULT_TAG_PREFIXES: [&str; 2] = ["v", ""];
+
 /// Get the latest version tag.
+///
+/// A tag is a version tag if its name is one of the `prefixes` followed by a
+/// `<major>.<minor>.<patch>` version.
 /// ## Returns
 /// [`VersionTag`] containing the latest version tag.
 pub fn get_latest_version_tag(
     repository: &Repository,
+    prefixes: &[String],
+) -> Result<Option<VersionTag>, Box<dyn Error>> {
+    get_latest_version_tag_with_scheme(repository, prefixes, VersionScheme::SemVer)
+}
+
+/// Get the latest version tag whose name is a version of the given [`VersionScheme`].
+/// ## Returns
+/// [`VersionTag`] containing the latest version tag.
+pub fn get_latest_version_tag_with_scheme(
+    repository: &Repository,
+    prefixes: &[String],
+    scheme: VersionScheme,
+) -> Result<Option<VersionTag>, Box<dyn Error>> {
+    let version_tags = get_version_tags(repository, prefixes, scheme)?;
+    Ok(version_tags
+        .into_iter()
+        .map(|(_, version_tag)| version_tag)
+        .max_by_key(|version_tag| version_tag.version.clone()))
+}
+
+/// Get all version tags of the repository, e.g. for generating a changelog of every release.
+/// ## Returns
+/// [`VersionTag`] of each version tag, sorted by their version in ascending order.
+pub fn get_all_version_tags(
+    repository: &Repository,
+    prefixes: &[String],
+) -> Result<Vec<VersionTag>, Box<dyn Error>> {
+    get_all_version_tags_with_scheme(repository, prefixes, VersionScheme::SemVer)
+}
+
+/// Get all version tags whose name is a version of the given [`VersionScheme`].
+/// ## Returns
+/// [`VersionTag`] of each version tag, sorted by their version in ascending order.
+pub fn get_all_version_tags_with_scheme(
+    repository: &Repository,
+    prefixes: &[String],
+    scheme: VersionScheme,
+) -> Result<Vec<VersionTag>, Box<dyn Error>> {
+    let mut version_tags: Vec<VersionTag> = get_version_tags(repository, prefixes, scheme)?
+        .into_iter()
+        .map(|(_, version_tag)| version_tag)
+        .collect();
+    version_tags.sort_by(|a, b| a.version.cmp(&b.version));
+    Ok(version_tags)
+}
+
+/// Get the latest version tag that is reachable from HEAD.
+///
+/// Unlike [`get_latest_version_tag`], tags of commits that are not ancestors of HEAD are
+/// ignored, e.g. higher versions released from other branches.
+/// ## Returns
+/// [`VersionTag`] containing the latest reachable version tag.
+pub fn get_latest_reachable_version_tag(
+    repository: &Repository,
+    prefixes: &[String],
 ) -> Result<Option<VersionTag>, Box<dyn Error>> {
+    let head_oid = match repository.head() {
+        Ok(head) => head.peel_to_commit()?.id(),
+        Err(error) if matches!(error.code(), ErrorCode::UnbornBranch | ErrorCode::NotFound) => {
+            return Ok(None)
+        }
+        Err(error) => return Err(error.into()),
+    };
+    get_latest_version_tag_reachable_from(repository, head_oid, prefixes)
+}
+
+/// Get the latest version tag that is reachable from the given commit, e.g. from the tip of
+/// the analyzed branch.
+/// ## Returns
+/// [`VersionTag`] containing the latest version tag reachable from `tip_oid`.
+pub fn get_latest_version_tag_reachable_from(
+    repository: &Repository,
+    tip_oid: Oid,
+    prefixes: &[String],
+) -> Result<Option<VersionTag>, Box<dyn Error>> {
+    let mut reachable_version_tags = Vec::new();
+    for (_, version_tag) in get_version_tags(repository, prefixes, VersionScheme::SemVer)? {
+        if version_tag.commit_oid == tip_oid
+            || repository.graph_descendant_of(tip_oid, version_tag.commit_oid)?
+        {
+            reachable_version_tags.push(version_tag);
+        }
+    }
+    Ok(reachable_version_tags
+        .into_iter()
+        .max_by_key(|version_tag| version_tag.version.clone()))
+}
+
+/// Find the versions that are tagged by more than one reference.
+/// ## Returns
+/// [`DuplicateVersionTag`] for each duplicated version.
+pub fn find_duplicate_version_tags(
+    repository: &Repository,
+    prefixes: &[String],
+) -> Result<Vec<DuplicateVersionTag>, Box<dyn Error>> {
+    let mut references_by_version: BTreeMap<Version, Vec<String>> = BTreeMap::new();
+    for (reference_name, version_tag) in
+        get_version_tags(repository, prefixes, VersionScheme::SemVer)?
+    {
+        references_by_version
+            .entry(version_tag.version)
+            .or_default()
+            .push(reference_name);
+    }
+    Ok(references_by_version
+        .into_iter()
+        .filter(|(_, reference_names)| reference_names.len() > 1)
+        .map(|(version, reference_names)| DuplicateVersionTag {
+            version,
+            reference_names,
+        })
+        .collect())
+}
+
+/// Find the tags that look like version tags, but their version can't be parsed, e.g.
+/// because a number of the version is out of range.
+/// ## Returns
+/// Names of the malformed version tags, which are skipped when looking for the latest
+/// version tag.
+pub fn find_malformed_version_tags(
+    repository: &Repository,
+    prefixes: &[String],
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let malformed_tags = repository
+        .references()?
+        .filter_map(|reference| reference.ok())
+        .filter(|reference| reference.is_tag())
+        .filter_map(|reference| reference.shorthand().map(String::from))
+        .filter(|tag_name| {
+            let mut version_numbers = VersionTag::version_numbers(tag_name, prefixes).peekable();
+            version_numbers.peek().is_some()
+                && version_numbers.all(|version_number| Version::parse(version_number).is_err())
+        })
+        .collect();
+    Ok(malformed_tags)
+}
+
+/// Get the version tags together with the names of their references.
+fn get_version_tags(
+    repository: &Repository,
+    prefixes: &[String],
+    scheme: VersionScheme,
+) -> Result<Vec<(String, VersionTag)>, Box<dyn Error>> {
     let references: Vec<Reference> = repository
         .references()?
         .filter_map(|reference| reference.ok())
         .collect();
 
-    let version_tags: Vec<VersionTag> = references
+    let tag_objects: Vec<(&Reference, Object)> = references
         .iter()
         .filter(|reference| reference.is_tag())
-        .filter_map(|reference| {
-            reference.target().and_then(|oid| {
-                repository
-                    .find_object(oid, None)
-                    .ok()
-                    .map(|object| (reference, object))
-            })
+        .filter_map(|reference| reference.target().map(|oid| (reference, oid)))
+        .map(|(reference, oid)| {
+            repository
+                .find_object(oid, None)
+                .map(|object| (reference, object))
+                .with_context(|| {
+                    format!(
+                        "failed to find object {oid} of reference {}",
+                        reference.name().unwrap_or_default()
+                    )
+                })
         })
+        .collect::<Result<_, _>>()?;
+
+    let version_tags = tag_objects
+        .into_iter()
         .filter_map(|(reference, object)| {
             Tag::from_object(object)
-                .and_then(|tag| VersionTag::from_annotated_tag(&tag))
-                .or_else(|| VersionTag::from_lightweight_tag(reference))
+                .and_then(|tag| VersionTag::from_annotated_tag(&tag, prefixes, scheme))
+                .or_else(|| VersionTag::from_lightweight_tag(reference, prefixes, scheme))
+                .map(|version_tag| {
+                    (
+                        reference.name().unwrap_or_default().to_string(),
+                        version_tag,
+                    )
+                })
         })
         .collect();
 
-    Ok(version_tags.iter().max().cloned())
+    Ok(version_tags)
 }
 
 trait AnnotatedTag {
@@ -54,12 +219,132 @@ impl AnnotatedTag for Tag<'_> {
 }
 
 /// A structure that represent a version tag.
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
+///
+/// Version tags are ordered by their [`version`](VersionTag::version) only, so two tags of
+/// the same version on different commits are neither less nor greater than each other.
+/// This ordering is deliberately coarser than equality, which still compares every field,
+/// including the [`commit_oid`](VersionTag::commit_oid): tags that compare as
+/// [`Ordering::Equal`] may still be unequal.
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct VersionTag {
     /// Semantic version parsed from the tag name.
     pub version: Version,
     /// Object ID of the commit that the tag points to.
     pub commit_oid: Oid,
+    /// Time of the commit that the tag points to, in seconds since the Unix epoch.
+    pub commit_time: i64,
+    /// Kind of the git tag.
+    pub kind: TagKind,
+}
+
+impl Ord for VersionTag {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.version.cmp(&other.version)
+    }
+}
+
+impl PartialOrd for VersionTag {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Enum to represent the kind of a git tag
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub enum TagKind {
+    /// Tag object with its own message, tagger and date
+    Annotated,
+    /// Reference that points directly to a commit
+    Lightweight,
+}
+
+/// Enum to represent the versioning scheme of the version tags
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum VersionScheme {
+    /// Semantic versions, e.g. `v1.2.3`
+    #[default]
+    SemVer,
+    /// Calendar versions in the `<year>.<month>.<micro>` form, e.g. `2024.05.0`. The
+    /// version is stored as a [`Version`] of the year, the month and the micro number, so
+    /// calendar versions are ordered like the tuples of their numbers.
+    CalVer,
+}
+
+impl VersionScheme {
+    /// Parse the version from the name of a tag according to the scheme, trying each
+    /// prefix in order.
+    ///
+    /// ## Returns
+    ///
+    /// The [`Version`] if the name is a version tag name of the scheme, `None` otherwise.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::{VersionScheme, DEFAULT_TAG_PREFIXES};
+    /// use semver::Version;
+    ///
+    /// let version = VersionScheme::CalVer.parse_tag_name("2024.05.0", &DEFAULT_TAG_PREFIXES);
+    /// assert_eq!(version, Some(Version::new(2024, 5, 0)));
+    /// ```
+    pub fn parse_tag_name<S: AsRef<str>>(&self, name: &str, prefixes: &[S]) -> Option<Version> {
+        match self {
+            VersionScheme::SemVer => VersionTag::parse_version_name(name, prefixes),
+            VersionScheme::CalVer => {
+                let calver_regex = Regex::new(r"^(\d{4})\.(\d{1,2})\.(\d+)$").unwrap();
+                prefixes
+                    .iter()
+                    .filter_map(|prefix| name.strip_prefix(prefix.as_ref()))
+                    .find_map(|version_number| {
+                        let captures = calver_regex.captures(version_number)?;
+                        let number = |index: usize| captures[index].parse::<u64>().ok();
+                        let month = number(2).filter(|month| (1..=12).contains(month))?;
+                        Some(Version::new(number(1)?, month, number(3)?))
+                    })
+            }
+        }
+    }
+
+    /// Format a version like it's written in the version tags of the scheme, e.g. the month
+    /// of calendar versions is padded to two digits.
+    pub fn format(&self, version: &Version) -> String {
+        match self {
+            VersionScheme::SemVer => version.to_string(),
+            VersionScheme::CalVer => {
+                format!("{}.{:02}.{}", version.major, version.minor, version.patch)
+            }
+        }
+    }
+}
+
+/// Compute the calendar version that follows `current` when releasing in the given year and
+/// month: the micro number is incremented within the same month and reset in a new month.
+///
+/// ## Example
+///
+/// ```
+/// use cargo_semantic_release::next_calver;
+/// use semver::Version;
+///
+/// let current = Version::new(2024, 5, 0);
+/// assert_eq!(next_calver(&current, 2024, 5), Version::new(2024, 5, 1));
+/// assert_eq!(next_calver(&current, 2024, 12), Version::new(2024, 12, 0));
+/// ```
+pub fn next_calver(current: &Version, year: u64, month: u64) -> Version {
+    if (year, month) > (current.major, current.minor) {
+        Version::new(year, month, 0)
+    } else {
+        Version::new(current.major, current.minor, current.patch + 1)
+    }
+}
+
+/// A structure that represent a version which is tagged by multiple references.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DuplicateVersionTag {
+    /// Semantic version parsed from the tags.
+    pub version: Version,
+    /// Names of the references that tag the version.
+    pub reference_names: Vec<String>,
 }
 
 impl VersionTag {
@@ -67,16 +352,16 @@ impl VersionTag {
     ///
     /// ## Returns
     ///
-    /// `Option` which is `Some` if the version tag is valid, `None` otherwise.
-    fn from_annotated_tag(tag: &Tag) -> Option<Self> {
-        let tag_name = tag.name().unwrap();
-        if !Self::is_valid_version_tag(tag_name) {
-            return None;
-        }
-        let version_number = tag_name.trim_start_matches("v");
+    /// `Option` which is `Some` if the version tag is valid, `None` otherwise, e.g. when
+    /// the tag points to a tree or a blob instead of a commit.
+    fn from_annotated_tag(tag: &Tag, prefixes: &[String], scheme: VersionScheme) -> Option<Self> {
+        let version = scheme.parse_tag_name(tag.name()?, prefixes)?;
+        let commit = tag.target().ok()?.peel_to_commit().ok()?;
         Some(Self {
-            version: Version::parse(version_number).unwrap(),
-            commit_oid: tag.target_id(),
+            version,
+            commit_oid: commit.id(),
+            commit_time: commit.time().seconds(),
+            kind: TagKind::Annotated,
         })
     }
 
@@ -85,30 +370,88 @@ impl VersionTag {
     /// ## Returns
     ///
     /// `Option` which is `Some` if the version tag is valid, `None` otherwise.
-    fn from_lightweight_tag(reference: &Reference) -> Option<Self> {
-        let tag_name = reference.shorthand().unwrap();
-        if !Self::is_valid_version_tag(tag_name) {
-            return None;
-        }
-        let version_number = tag_name.trim_start_matches("v");
+    fn from_lightweight_tag(
+        reference: &Reference,
+        prefixes: &[String],
+        scheme: VersionScheme,
+    ) -> Option<Self> {
+        let version = scheme.parse_tag_name(reference.shorthand()?, prefixes)?;
+        let commit = reference.peel_to_commit().ok()?;
         Some(Self {
-            version: Version::parse(version_number).unwrap(),
-            commit_oid: reference.target().unwrap(),
+            version,
+            commit_oid: commit.id(),
+            commit_time: commit.time().seconds(),
+            kind: TagKind::Lightweight,
         })
     }
 
-    fn is_valid_version_tag(tag_name: &str) -> bool {
-        let version_regex = Regex::new(r"^v\d+\.\d+\.\d+$").unwrap();
-        version_regex.is_match(tag_name)
+    /// Check if the version tag is an annotated tag.
+    pub fn is_annotated(&self) -> bool {
+        self.kind == TagKind::Annotated
+    }
+
+    /// Check if the tagged commit is older than the given number of days.
+    ///
+    /// ## Returns
+    ///
+    /// `true` if more than `days` passed between the commit time and `now`, which is
+    /// given in seconds since the Unix epoch.
+    pub fn is_older_than(&self, days: u64, now: i64) -> bool {
+        let age = now.saturating_sub(self.commit_time);
+        age > 0 && age as u64 > days.saturating_mul(24 * 60 * 60)
+    }
+
+    /// Parse the version from the name of a tag, trying each prefix in order.
+    ///
+    /// A name is a version tag name if it's one of the `prefixes` followed by a
+    /// `<major>.<minor>.<patch>` version. Versions that don't fit into the version numbers,
+    /// e.g. `v99999999999999999999.0.0`, are skipped instead of failing.
+    ///
+    /// ## Returns
+    ///
+    /// The [`Version`] if the name is a version tag name, `None` otherwise.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use cargo_semantic_release::{VersionTag, DEFAULT_TAG_PREFIXES};
+    /// use semver::Version;
+    ///
+    /// let version = VersionTag::parse_version_name("v1.2.3", &DEFAULT_TAG_PREFIXES);
+    /// assert_eq!(version, Some(Version::new(1, 2, 3)));
+    /// ```
+    pub fn parse_version_name<S: AsRef<str>>(name: &str, prefixes: &[S]) -> Option<Version> {
+        Self::version_numbers(name, prefixes)
+            .find_map(|version_number| Version::parse(version_number).ok())
+    }
+
+    /// Strip each prefix from the name of a tag and keep the `<major>.<minor>.<patch>`
+    /// remainders.
+    fn version_numbers<'a, S: AsRef<str>>(
+        tag_name: &'a str,
+        prefixes: &'a [S],
+    ) -> impl Iterator<Item = &'a str> {
+        let version_regex = Regex::new(r"^\d+\.\d+\.\d+$").unwrap();
+        prefixes
+            .iter()
+            .filter_map(|prefix| tag_name.strip_prefix(prefix.as_ref()))
+            .filter(move |version_number| version_regex.is_match(version_number))
     }
 }
 
 #[cfg(test)]
 mod version_tag_tests {
+    use crate::repo::version_tag::DEFAULT_TAG_PREFIXES;
     pub use crate::repo::RepositoryExtension;
+    use crate::repo::{next_calver, DuplicateVersionTag, TagKind, VersionScheme, VersionTag};
     use crate::test_util::repo_init;
     pub use crate::test_util::RepositoryTestExtensions;
+    use git2::Oid;
     use semver::Version;
+    use std::cmp::Ordering;
+    use std::fs;
+
+    const DAY: i64 = 24 * 60 * 60;
 
     #[test]
     fn repository_does_not_have_tags() {
@@ -136,6 +479,65 @@ mod version_tag_tests {
         assert!(result.is_none(), "Expected None, but got Some")
     }
 
+    #[test]
+    fn comparing_calendar_version_tags() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec!["commit 1", "commit 2", "commit 3"]));
+        repository.add_tag(
+            repository.find_commit_by_message("commit 1").unwrap(),
+            "2024.05.0",
+        );
+        repository.add_tag(
+            repository.find_commit_by_message("commit 2").unwrap(),
+            "2024.12.1",
+        );
+        repository.add_tag(
+            repository.find_commit_by_message("commit 3").unwrap(),
+            "v1.0.0",
+        );
+        let prefixes = DEFAULT_TAG_PREFIXES.map(String::from);
+
+        // When
+        let result = repository
+            .latest_version_tag_with_scheme(&prefixes, VersionScheme::CalVer)
+            .unwrap()
+            .unwrap();
+
+        // Then
+        assert_eq!(result.version, Version::new(2024, 12, 1));
+        assert_eq!(VersionScheme::CalVer.format(&result.version), "2024.12.1");
+        assert!(
+            VersionScheme::CalVer.parse_tag_name("2024.05.0", &prefixes)
+                < VersionScheme::CalVer.parse_tag_name("2024.12.1", &prefixes)
+        );
+    }
+
+    #[test]
+    fn parsing_invalid_calendar_version_names() {
+        for name in ["1.0.0", "2024.13.0", "2024.00.0", "24.05.0", "2024.05"] {
+            assert_eq!(
+                VersionScheme::CalVer.parse_tag_name(name, &DEFAULT_TAG_PREFIXES),
+                None,
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn computing_next_calendar_version() {
+        // Given
+        let current = Version::new(2024, 5, 0);
+
+        // Then
+        assert_eq!(next_calver(&current, 2024, 5), Version::new(2024, 5, 1));
+        assert_eq!(next_calver(&current, 2024, 12), Version::new(2024, 12, 0));
+        assert_eq!(next_calver(&current, 2025, 1), Version::new(2025, 1, 0));
+        assert_eq!(
+            VersionScheme::CalVer.format(&next_calver(&current, 2024, 5)),
+            "2024.05.1"
+        );
+    }
+
     #[test]
     fn repository_has_one_annotated_version_tag() {
         // Given
@@ -149,6 +551,7 @@ mod version_tag_tests {
 
         // Then
         assert_eq!(result.version, Version::parse("1.0.0").unwrap());
+        assert_eq!(result.kind, TagKind::Annotated);
         assert_eq!(
             result.commit_oid,
             repository
@@ -174,6 +577,7 @@ mod version_tag_tests {
 
         // Then
         assert_eq!(result.version, Version::parse("1.0.0").unwrap());
+        assert_eq!(result.kind, TagKind::Lightweight);
         assert_eq!(
             result.commit_oid,
             repository
@@ -214,4 +618,373 @@ mod version_tag_tests {
             "Object IDs don't match"
         );
     }
+
+    #[test]
+    fn listing_all_version_tags_sorted() {
+        // Given
+        let commit_messages = vec![
+            ":tada: initial release",
+            ":sparkles: new feature",
+            ":bug: fix bug",
+        ];
+        let (_temp_dir, repository) = repo_init(Some(commit_messages.clone()));
+        let tags = vec!["v1.0.0", "v1.1.0", "v1.0.1"];
+        commit_messages
+            .iter()
+            .map(|commit| repository.find_commit_by_message(commit).unwrap())
+            .zip(tags)
+            .for_each(|(commit, tag)| repository.add_tag(commit, tag));
+        repository.add_tag(
+            repository.find_commit_by_message(":bug: fix bug").unwrap(),
+            "not-a-version",
+        );
+
+        // When
+        let result = repository.all_version_tags().unwrap();
+
+        // Then
+        let versions: Vec<Version> = result
+            .into_iter()
+            .map(|version_tag| version_tag.version)
+            .collect();
+        assert_eq!(
+            versions,
+            vec![
+                Version::new(1, 0, 0),
+                Version::new(1, 0, 1),
+                Version::new(1, 1, 0)
+            ]
+        );
+    }
+
+    fn prefixes(prefixes: &[&str]) -> Vec<String> {
+        prefixes.iter().map(|prefix| prefix.to_string()).collect()
+    }
+
+    #[test]
+    fn parsing_version_tags_with_default_prefixes() {
+        // Given
+        let default_prefixes = prefixes(&DEFAULT_TAG_PREFIXES);
+
+        // Then
+        assert_eq!(
+            VersionTag::parse_version_name("v1.0.0", &default_prefixes),
+            Some(Version::new(1, 0, 0))
+        );
+        assert_eq!(
+            VersionTag::parse_version_name("1.0.0", &default_prefixes),
+            Some(Version::new(1, 0, 0))
+        );
+        assert_eq!(
+            VersionTag::parse_version_name("release-1.0.0", &default_prefixes),
+            None
+        );
+        assert_eq!(
+            VersionTag::parse_version_name("v01.0.0", &default_prefixes),
+            None
+        );
+    }
+
+    #[test]
+    fn parsing_version_tags_with_custom_prefixes() {
+        // Given
+        let custom_prefixes = prefixes(&["release-", "rel/"]);
+
+        // Then
+        assert_eq!(
+            VersionTag::parse_version_name("release-1.0.0", &custom_prefixes),
+            Some(Version::new(1, 0, 0))
+        );
+        assert_eq!(
+            VersionTag::parse_version_name("rel/2.1.0", &custom_prefixes),
+            Some(Version::new(2, 1, 0))
+        );
+        assert_eq!(
+            VersionTag::parse_version_name("v1.0.0", &custom_prefixes),
+            None
+        );
+    }
+
+    #[test]
+    fn parsing_invalid_version_tag_names() {
+        for name in ["v1.0", "v1.0.0-rc.1", "1.0.0v", "vv1.0.0", ""] {
+            assert_eq!(
+                VersionTag::parse_version_name(name, &DEFAULT_TAG_PREFIXES),
+                None,
+                "{name} is not a version tag name"
+            );
+        }
+    }
+
+    #[test]
+    fn repository_has_version_tag_with_custom_prefix() {
+        // Given
+        let commit_message = ":tada: initial release";
+        let (_temp_dir, repository) = repo_init(Some(vec![commit_message]));
+        let commit = repository.find_commit_by_message(commit_message).unwrap();
+        repository.add_tag(commit, "release-1.0.0");
+
+        // When
+        let result = repository
+            .get_latest_version_tag_with_prefixes(&prefixes(&["release-"]))
+            .unwrap()
+            .unwrap();
+
+        // Then
+        assert_eq!(result.version, Version::new(1, 0, 0));
+    }
+
+    #[test]
+    fn version_tag_has_commit_time() {
+        // Given
+        let commit_message = ":tada: initial release";
+        let (_temp_dir, repository) = repo_init(Some(vec![commit_message]));
+        let commit = repository.find_commit_by_message(commit_message).unwrap();
+        let commit_time = commit.time().seconds();
+        repository.add_tag(commit, "v1.0.0");
+
+        // When
+        let result = repository.get_latest_version_tag().unwrap().unwrap();
+
+        // Then
+        assert_eq!(result.commit_time, commit_time);
+    }
+
+    #[test]
+    fn fresh_version_tag_is_not_older_than_threshold() {
+        // Given
+        let commit_message = ":tada: initial release";
+        let (_temp_dir, repository) = repo_init(Some(vec![commit_message]));
+        let commit = repository.find_commit_by_message(commit_message).unwrap();
+        repository.add_tag(commit, "v1.0.0");
+        let version_tag = repository.get_latest_version_tag().unwrap().unwrap();
+        let now = version_tag.commit_time + 2 * DAY;
+
+        // When
+        let result = version_tag.is_older_than(7, now);
+
+        // Then
+        assert!(!result, "Expected fresh tag, but it is stale");
+    }
+
+    #[test]
+    fn stale_version_tag_is_older_than_threshold() {
+        // Given
+        let commit_message = ":tada: initial release";
+        let (_temp_dir, repository) = repo_init(Some(vec![commit_message]));
+        let commit = repository.find_commit_by_message(commit_message).unwrap();
+        repository.add_tag(commit, "v1.0.0");
+        let version_tag = repository.get_latest_version_tag().unwrap().unwrap();
+        let now = version_tag.commit_time + 30 * DAY;
+
+        // When
+        let result = version_tag.is_older_than(7, now);
+
+        // Then
+        assert!(result, "Expected stale tag, but it is fresh");
+    }
+
+    #[test]
+    fn repository_without_duplicate_version_tags() {
+        // Given
+        let commit_messages = vec![":tada: initial release", ":sparkles: new feature"];
+        let (_temp_dir, repository) = repo_init(Some(commit_messages.clone()));
+        let tags = vec!["v1.0.0", "v1.1.0"];
+        commit_messages
+            .iter()
+            .map(|commit| repository.find_commit_by_message(commit).unwrap())
+            .zip(tags)
+            .for_each(|(commit, tag)| repository.add_tag(commit, tag));
+
+        // When
+        let result = repository
+            .find_duplicate_version_tags(&prefixes(&DEFAULT_TAG_PREFIXES))
+            .unwrap();
+
+        // Then
+        assert!(
+            result.is_empty(),
+            "Expected no duplicates, but got {result:?}"
+        )
+    }
+
+    #[test]
+    fn repository_with_duplicate_version_tags() {
+        // Given
+        let commit_messages = vec![":tada: initial release", ":sparkles: new feature"];
+        let (_temp_dir, repository) = repo_init(Some(commit_messages.clone()));
+        let commit = repository
+            .find_commit_by_message(commit_messages[0])
+            .unwrap();
+        repository.add_tag(commit, "v1.2.0");
+        let annotated_tag_oid = repository
+            .find_reference("refs/tags/v1.2.0")
+            .unwrap()
+            .target()
+            .unwrap();
+        repository
+            .reference("refs/tags/release", annotated_tag_oid, false, "")
+            .unwrap();
+
+        // When
+        let result = repository
+            .find_duplicate_version_tags(&prefixes(&DEFAULT_TAG_PREFIXES))
+            .unwrap();
+
+        // Then
+        assert_eq!(
+            result,
+            vec![DuplicateVersionTag {
+                version: Version::new(1, 2, 0),
+                reference_names: vec![
+                    "refs/tags/release".to_string(),
+                    "refs/tags/v1.2.0".to_string()
+                ]
+            }]
+        );
+    }
+
+    #[test]
+    fn version_tag_out_of_range_is_skipped() {
+        // Given
+        let commit_messages = vec![":tada: initial release", ":sparkles: new feature"];
+        let (_temp_dir, repository) = repo_init(Some(commit_messages.clone()));
+        let tags = vec!["v1.0.0", "v99999999999999999999.0.0"];
+        commit_messages
+            .iter()
+            .map(|commit| repository.find_commit_by_message(commit).unwrap())
+            .zip(tags)
+            .for_each(|(commit, tag)| repository.add_tag(commit, tag));
+
+        // When
+        let latest_version_tag = repository.get_latest_version_tag().unwrap();
+        let malformed_tags = repository
+            .find_malformed_version_tags(&prefixes(&DEFAULT_TAG_PREFIXES))
+            .unwrap();
+
+        // Then
+        assert_eq!(latest_version_tag.unwrap().version, Version::new(1, 0, 0));
+        assert_eq!(malformed_tags, vec!["v99999999999999999999.0.0"]);
+    }
+
+    #[test]
+    fn higher_version_tag_on_other_branch_is_not_reachable() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec![":tada: initial release"]));
+        let release_commit = repository
+            .find_commit_by_message(":tada: initial release")
+            .unwrap();
+        repository.add_tag(release_commit.clone(), "v1.0.0");
+        repository.branch("next", &release_commit, false).unwrap();
+        repository.set_head("refs/heads/next").unwrap();
+        repository.add_commit(":boom: remove the old API");
+        let next_commit = repository
+            .find_commit_by_message(":boom: remove the old API")
+            .unwrap();
+        repository.add_tag(next_commit, "v2.0.0");
+        repository.set_head("refs/heads/main").unwrap();
+        repository.add_commit(":ambulance: hotfix");
+
+        // When
+        let latest_version_tag = repository.get_latest_version_tag().unwrap().unwrap();
+        let reachable_version_tag = repository.latest_reachable_version_tag().unwrap().unwrap();
+
+        // Then
+        assert_eq!(latest_version_tag.version, Version::new(2, 0, 0));
+        assert_eq!(reachable_version_tag.version, Version::new(1, 0, 0));
+    }
+
+    #[test]
+    fn version_tag_only_in_packed_refs() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec![":tada: initial release"]));
+        let commit = repository
+            .find_commit_by_message(":tada: initial release")
+            .unwrap();
+        repository.add_tag(commit, "v1.0.0");
+        let tag_oid = repository
+            .find_reference("refs/tags/v1.0.0")
+            .unwrap()
+            .target()
+            .unwrap();
+        fs::remove_file(repository.path().join("refs/tags/v1.0.0")).unwrap();
+        fs::write(
+            repository.path().join("packed-refs"),
+            format!("# pack-refs with: peeled fully-peeled sorted \n{tag_oid} refs/tags/v1.0.0\n"),
+        )
+        .unwrap();
+
+        // When
+        let result = repository.get_latest_version_tag().unwrap();
+
+        // Then
+        assert_eq!(result.unwrap().version, Version::new(1, 0, 0));
+    }
+
+    #[test]
+    fn version_tag_of_tree_is_skipped() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec![":tada: initial release"]));
+        let commit = repository
+            .find_commit_by_message(":tada: initial release")
+            .unwrap();
+        repository.add_tag(commit.clone(), "v1.0.0");
+        let tree = commit.tree().unwrap();
+        let signature = repository.signature().unwrap();
+        repository
+            .tag("v2.0.0", tree.as_object(), &signature, "", false)
+            .unwrap();
+        repository
+            .tag_lightweight("v3.0.0", tree.as_object(), false)
+            .unwrap();
+
+        // When
+        let result = repository.get_latest_version_tag().unwrap();
+
+        // Then
+        assert_eq!(result.unwrap().version, Version::new(1, 0, 0));
+    }
+
+    #[test]
+    fn tag_pointing_to_missing_object() {
+        // Given
+        let (_temp_dir, repository) = repo_init(Some(vec![":tada: initial release"]));
+        let missing_oid = "3c8e19bf7fe7ba3dd6f0489374a5200fad124377";
+        fs::write(
+            repository.path().join("refs/tags/v1.0.0"),
+            format!("{missing_oid}\n"),
+        )
+        .unwrap();
+
+        // When
+        let result = repository.get_latest_version_tag();
+
+        // Then
+        let error = result.expect_err("Expected an error, but got Ok");
+        assert!(
+            error.to_string().contains(missing_oid) && error.to_string().contains("v1.0.0"),
+            "error message doesn't name the object and the reference: {error}"
+        );
+    }
+
+    #[test]
+    fn version_tags_of_same_version_are_ordered_equally() {
+        // Given
+        let version_tag = |oid: &str| VersionTag {
+            version: Version::new(1, 0, 0),
+            commit_oid: Oid::from_str(oid).unwrap(),
+            commit_time: 0,
+            kind: TagKind::Annotated,
+        };
+        let first = version_tag("1111111111111111111111111111111111111111");
+        let second = version_tag("2222222222222222222222222222222222222222");
+
+        // Then
+        assert_eq!(first.cmp(&second), Ordering::Equal);
+        assert_eq!(second.cmp(&first), Ordering::Equal);
+        assert_ne!(
+            first, second,
+            "Expected tags of different commits to differ"
+        );
+    }
 }
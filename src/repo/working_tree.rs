@@ -0,0 +1,49 @@
+use git2::{Repository, StatusOptions};
+use std::error::Error;
+
+/// Check whether the working tree of the repository has uncommitted changes.
+///
+/// Staged, unstaged and untracked changes make the working tree dirty, ignored files don't.
+/// ## Returns
+/// `true` if there are no uncommitted changes, `false` otherwise.
+pub fn is_clean(repository: &Repository) -> Result<bool, Box<dyn Error>> {
+    let mut options = StatusOptions::new();
+    options.include_untracked(true).include_ignored(false);
+    let statuses = repository.statuses(Some(&mut options))?;
+    Ok(statuses.is_empty())
+}
+
+#[cfg(test)]
+mod working_tree_tests {
+    pub use crate::repo::RepositoryExtension;
+    use crate::test_util::repo_init;
+    pub use crate::test_util::RepositoryTestExtensions;
+    use std::fs;
+
+    #[test]
+    fn committed_repository_is_clean() {
+        // Given
+        let (_temp_dir, repository) = repo_init(None);
+        repository.add_commit_with_file(":tada: initial commit", "README.md");
+
+        // When
+        let result = repository.is_clean().unwrap();
+
+        // Then
+        assert!(result, "Expected clean working tree");
+    }
+
+    #[test]
+    fn repository_with_modified_file_is_dirty() {
+        // Given
+        let (temp_dir, repository) = repo_init(None);
+        repository.add_commit_with_file(":tada: initial commit", "README.md");
+        fs::write(temp_dir.path().join("README.md"), "modified").unwrap();
+
+        // When
+        let result = repository.is_clean().unwrap();
+
+        // Then
+        assert!(!result, "Expected dirty working tree");
+    }
+}
@@ -0,0 +1,297 @@
+use crate::changes::{ChangeCounts, SemanticVersionAction};
+use crate::intention::Category;
+use chrono::{DateTime, FixedOffset};
+use semver::Version;
+
+/// Structured result of the analysis, without any formatting.
+///
+/// Can be created with [`Changes::report`] method, then rendered by the consumers as
+/// they need, e.g. into a changelog.
+///
+/// [`Changes::report`]: crate::Changes::report
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    /// Number of commits in each change category
+    pub counts: ChangeCounts,
+    /// Number of commits that were analyzed, including the ones without a known intention
+    pub total_commits_analyzed: usize,
+    /// Suggested action for the semantic version
+    pub action: SemanticVersionAction,
+    /// Version after applying the suggested action, if the current version is known
+    pub next_version: Option<Version>,
+    /// Category, subject, short hash and author date of each commit, ordered from the
+    /// major to the other changes. The date is `None` if the commit time is unknown.
+    pub entries: Vec<(Category, String, String, Option<DateTime<FixedOffset>>)>,
+}
+
+impl Report {
+    /// Render the report as a Markdown changelog section, e.g. for the notes of a release.
+    ///
+    /// The section is headed by the next version, or by `Unreleased` if it's unknown. The
+    /// commits are listed under a heading per change category. When the version is kept,
+    /// only `No release necessary.` is rendered.
+    pub fn to_changelog_markdown(&self) -> String {
+        self.render_changelog(None, None)
+    }
+
+    /// Render the report as a Markdown changelog section like
+    /// [`Report::to_changelog_markdown`], but show the author date of the commits next to
+    /// their hashes, e.g. `- :bug: fix the parser (3c8e19b, 2024-05-01)`.
+    ///
+    /// The dates are formatted in the timezone of their author with the strftime-style
+    /// `date_format`, e.g. `%Y-%m-%d`. Commits without a known time are listed without a
+    /// date.
+    pub fn to_changelog_markdown_with_dates(&self, date_format: &str) -> String {
+        self.render_changelog(None, Some(date_format))
+    }
+
+    /// Render the report as a Markdown changelog section like
+    /// [`Report::to_changelog_markdown`], but head it by the given `version_name` instead of
+    /// the next version with a `v` prefix, e.g. by the name of the version tag to create like
+    /// `2024.05.0`. The dates are shown if a `date_format` is given, like with
+    /// [`Report::to_changelog_markdown_with_dates`].
+    pub fn to_named_changelog_markdown(
+        &self,
+        version_name: &str,
+        date_format: Option<&str>,
+    ) -> String {
+        self.render_changelog(Some(version_name), date_format)
+    }
+
+    /// Render the report as the Markdown changelog section of a past release, e.g. for a
+    /// complete changelog of every release.
+    ///
+    /// Unlike [`Report::to_changelog_markdown`], the section is headed by the given
+    /// `version_name`, e.g. the name of the version tag of the release like `v1.0.0`, or by
+    /// `Unreleased` if it's `None`, and the commits are listed even if they don't change the
+    /// version.
+    pub fn to_release_markdown(
+        &self,
+        version_name: Option<&str>,
+        date_format: Option<&str>,
+    ) -> String {
+        self.render_section(version_name, date_format)
+    }
+
+    fn render_changelog(&self, version_name: Option<&str>, date_format: Option<&str>) -> String {
+        if self.action == SemanticVersionAction::Keep {
+            return "No release necessary.\n".to_string();
+        }
+        let default_version_name = self
+            .next_version
+            .as_ref()
+            .map(|next_version| format!("v{next_version}"));
+        self.render_section(
+            version_name.or(default_version_name.as_deref()),
+            date_format,
+        )
+    }
+
+    fn render_section(&self, version_name: Option<&str>, date_format: Option<&str>) -> String {
+        let mut markdown = match version_name {
+            Some(version_name) => format!("## {version_name}\n"),
+            None => "## Unreleased\n".to_string(),
+        };
+        for category in [
+            Category::Major,
+            Category::Minor,
+            Category::Patch,
+            Category::Other,
+        ] {
+            let mut entries = self
+                .entries
+                .iter()
+                .filter(|(entry_category, _, _, _)| *entry_category == category)
+                .peekable();
+            if entries.peek().is_none() {
+                continue;
+            }
+            markdown.push_str(&format!("\n### {}\n\n", changelog_heading(category)));
+            for (_, subject, short_hash, date) in entries {
+                match date_format.zip(date.as_ref()) {
+                    Some((date_format, date)) => markdown.push_str(&format!(
+                        "- {subject} ({short_hash}, {})\n",
+                        date.format(date_format)
+                    )),
+                    None => markdown.push_str(&format!("- {subject} ({short_hash})\n")),
+                }
+            }
+        }
+        markdown
+    }
+}
+
+/// Heading of the changelog section of a change category.
+fn changelog_heading(category: Category) -> &'static str {
+    match category {
+        Category::Major => "Breaking changes",
+        Category::Minor => "Features",
+        Category::Patch => "Fixes",
+        Category::Other => "Other changes",
+    }
+}
+
+#[cfg(test)]
+mod report_tests {
+    use crate::changes::{ChangeCounts, Changes, SemanticVersionAction};
+    use crate::intention::Category;
+    use crate::repo::ConventionalCommit;
+    use semver::Version;
+
+    fn commit(message: &str, hash: &str) -> ConventionalCommit {
+        ConventionalCommit {
+            message: message.to_string(),
+            hash: hash.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn reporting_mixed_changes() {
+        // Given
+        let changes = Changes::from_commits(vec![
+            commit(":memo: update docs\n\nlonger description", "1111111aaaa"),
+            commit(":bug: fix the parser", "2222222bbbb"),
+            commit(":sparkles: add feature", "3333333cccc"),
+        ]);
+
+        // When
+        let result = changes.report(Some(Version::new(1, 2, 3)));
+
+        // Then
+        assert_eq!(
+            result.counts,
+            ChangeCounts {
+                major: 0,
+                minor: 1,
+                patch: 1,
+                other: 1
+            }
+        );
+        assert_eq!(result.action, SemanticVersionAction::IncrementMinor);
+        assert_eq!(result.next_version, Some(Version::new(1, 3, 0)));
+        assert_eq!(
+            result.entries,
+            vec![
+                (
+                    Category::Minor,
+                    ":sparkles: add feature".to_string(),
+                    "3333333".to_string(),
+                    None
+                ),
+                (
+                    Category::Patch,
+                    ":bug: fix the parser".to_string(),
+                    "2222222".to_string(),
+                    None
+                ),
+                (
+                    Category::Other,
+                    ":memo: update docs".to_string(),
+                    "1111111".to_string(),
+                    None
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn reporting_without_current_version() {
+        // Given
+        let changes = Changes::from_commits(vec![commit(":boom: remove the old API", "abc")]);
+
+        // When
+        let result = changes.report(None);
+
+        // Then
+        assert_eq!(result.action, SemanticVersionAction::IncrementMajor);
+        assert!(result.next_version.is_none(), "Expected None, but got Some");
+    }
+
+    #[test]
+    fn rendering_changelog_markdown() {
+        // Given
+        let changes = Changes::from_commits(vec![
+            commit(":bug: fix the parser\n\nlonger description", "2222222bbbb"),
+            commit(":sparkles: add feature", "3333333cccc"),
+        ]);
+        let report = changes.report(Some(Version::new(1, 2, 3)));
+
+        // When
+        let result = report.to_changelog_markdown();
+
+        // Then
+        assert_eq!(
+            result,
+            "## v1.3.0\n\n### Features\n\n- :sparkles: add feature (3333333)\n\n### Fixes\n\n- :bug: fix the parser (2222222)\n"
+        );
+    }
+
+    #[test]
+    fn rendering_changelog_markdown_without_release() {
+        // Given
+        let changes = Changes::from_commits(vec![commit(":memo: update docs", "1111111aaaa")]);
+
+        // When
+        let result = changes.report(None).to_changelog_markdown();
+
+        // Then
+        assert_eq!(result, "No release necessary.\n");
+    }
+
+    #[test]
+    fn rendering_release_markdown_without_version_change() {
+        // Given
+        let changes = Changes::from_commits(vec![commit(":memo: update docs", "1111111aaaa")]);
+
+        // When
+        let result = changes
+            .report(None)
+            .to_release_markdown(Some("v1.0.1"), None);
+
+        // Then
+        assert_eq!(
+            result,
+            "## v1.0.1\n\n### Other changes\n\n- :memo: update docs (1111111)\n"
+        );
+    }
+
+    #[test]
+    fn rendering_changelog_markdown_with_dates() {
+        // Given
+        let changes = Changes::from_commits(vec![
+            ConventionalCommit {
+                author_time: 1_714_600_800,
+                author_offset: 120,
+                ..commit(":sparkles: add feature", "3333333cccc")
+            },
+            commit(":bug: fix the parser", "2222222bbbb"),
+        ]);
+        let report = changes.report(Some(Version::new(1, 2, 3)));
+
+        // When
+        let result = report.to_changelog_markdown_with_dates("%Y-%m-%d %H:%M %z");
+
+        // Then
+        assert_eq!(
+            result,
+            "## v1.3.0\n\n### Features\n\n- :sparkles: add feature (3333333, 2024-05-02 00:00 +0200)\n\n### Fixes\n\n- :bug: fix the parser (2222222)\n"
+        );
+    }
+
+    #[test]
+    fn rendering_named_changelog_markdown() {
+        // Given
+        let changes = Changes::from_commits(vec![commit(":bug: fix the parser", "2222222bbbb")]);
+        let report = changes.report(Some(Version::new(2024, 5, 0)));
+
+        // When
+        let result = report.to_named_changelog_markdown("2024.05.1", None);
+
+        // Then
+        assert_eq!(
+            result,
+            "## 2024.05.1\n\n### Fixes\n\n- :bug: fix the parser (2222222)\n"
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use crate::changes::{ChangeCounts, Changes, SemanticVersionAction};
+use semver::Version;
+
+/// Structure that summarizes the analysis of a git repository
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, schemars::JsonSchema))]
+pub struct Summary {
+    /// Suggested action for the semantic version
+    pub action: SemanticVersionAction,
+    /// Version of the latest version tag, if there is any
+    pub current_version: Option<String>,
+    /// Version after applying the suggested action, if the current version is known
+    pub next_version: Option<String>,
+    /// Number of commits in each change category
+    pub counts: ChangeCounts,
+    /// Number of commits that were analyzed, including the ones without a known intention
+    pub total_commits_analyzed: usize,
+    /// Commits in each change category
+    pub changes: Changes,
+}
+
+impl Summary {
+    /// Summarize the changes of a repository and the action defined from them.
+    ///
+    /// ## Returns
+    ///
+    /// The [`Summary`] with the next version computed from the current version.
+    pub fn new(
+        changes: &Changes,
+        action: SemanticVersionAction,
+        current_version: Option<&Version>,
+    ) -> Self {
+        Self {
+            current_version: current_version.map(|version| version.to_string()),
+            next_version: current_version.map(|version| action.next_version(version).to_string()),
+            action,
+            counts: changes.counts(),
+            total_commits_analyzed: changes.total_commits_analyzed(),
+            changes: changes.clone(),
+        }
+    }
+
+    /// Serialize the summary into pretty printed JSON.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Generate the JSON Schema of the JSON serialized [`Summary`].
+///
+/// ## Returns
+///
+/// The pretty printed JSON Schema.
+#[cfg(feature = "serde")]
+pub fn json_schema() -> String {
+    let schema = schemars::schema_for!(Summary);
+    serde_json::to_string_pretty(&schema).unwrap()
+}
+
+#[cfg(test)]
+mod summary_tests {
+    use crate::changes::{ChangeCounts, Changes, SemanticVersionAction};
+    use crate::summary::Summary;
+    use semver::Version;
+
+    #[test]
+    fn summarizing_changes() {
+        // Given
+        let changes = Changes::default();
+
+        // When
+        let result = Summary::new(
+            &changes,
+            SemanticVersionAction::IncrementMinor,
+            Some(&Version::new(1, 2, 3)),
+        );
+
+        // Then
+        assert_eq!(result.current_version.as_deref(), Some("1.2.3"));
+        assert_eq!(result.next_version.as_deref(), Some("1.3.0"));
+        assert_eq!(result.counts, ChangeCounts::default());
+    }
+
+    #[test]
+    fn summarizing_changes_without_current_version() {
+        // When
+        let result = Summary::new(&Changes::default(), SemanticVersionAction::Keep, None);
+
+        // Then
+        assert!(
+            result.current_version.is_none(),
+            "Expected None, but got Some"
+        );
+        assert!(result.next_version.is_none(), "Expected None, but got Some");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializing_to_json() {
+        // Given
+        let summary = Summary::new(
+            &Changes::default(),
+            SemanticVersionAction::IncrementPatch,
+            Some(&Version::new(1, 2, 3)),
+        );
+
+        // When
+        let result = summary.to_json().unwrap();
+
+        // Then
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(json["action"], "increment_patch");
+        assert_eq!(json["next_version"], "1.2.4");
+        assert_eq!(json["counts"]["major"], 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn json_schema_describes_summary() {
+        // When
+        let result = crate::summary::json_schema();
+
+        // Then
+        let schema: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let properties = schema["properties"].as_object().unwrap();
+        for property in [
+            "action",
+            "current_version",
+            "next_version",
+            "counts",
+            "changes",
+        ] {
+            assert!(
+                properties.contains_key(property),
+                "schema doesn't have the {property} property"
+            );
+        }
+    }
+}
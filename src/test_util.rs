@@ -1,6 +1,8 @@
 use git2::{Commit, Repository, RepositoryInitOptions, Revwalk, Signature};
 use std::error::Error;
 use std::fmt;
+use std::fs;
+use std::path::Path;
 use tempfile::TempDir;
 
 #[doc(hidden)]
@@ -8,29 +10,76 @@ use tempfile::TempDir;
 /// Create an empty git repository in a temporary directory.
 /// # Returns
 /// The handler for the temporary directory and for the git repository.
+/// # Panics
+/// If the repository can't be created, see [`try_repo_init`] for the fallible variant.
 pub fn repo_init(commits: Option<Vec<&str>>) -> (TempDir, Repository) {
-    let temp_dir = TempDir::new().unwrap();
+    try_repo_init(commits).unwrap()
+}
+
+#[allow(dead_code)]
+/// Create an empty git repository in a temporary directory, like [`repo_init`], but return
+/// an error instead of panicking, e.g. for using it in the tests of other crates.
+/// # Returns
+/// The handler for the temporary directory and for the git repository, or the error of
+/// creating them.
+pub fn try_repo_init(commits: Option<Vec<&str>>) -> Result<(TempDir, Repository), Box<dyn Error>> {
+    try_repo_init_in(&std::env::temp_dir(), commits)
+}
+
+#[allow(dead_code)]
+/// Create an empty git repository in a temporary directory under `parent`, like
+/// [`try_repo_init`].
+/// # Returns
+/// The handler for the temporary directory and for the git repository, or the error of
+/// creating them, e.g. when `parent` doesn't exist.
+pub fn try_repo_init_in(
+    parent: &Path,
+    commits: Option<Vec<&str>>,
+) -> Result<(TempDir, Repository), Box<dyn Error>> {
+    let temp_dir = TempDir::new_in(parent)
+        .map_err(|error| format!("can't create directory in {}: {error}", parent.display()))?;
     let mut opts = RepositoryInitOptions::new();
     opts.initial_head("main");
-    let repo = Repository::init_opts(temp_dir.path(), &opts).unwrap();
-    let mut config = repo.config().unwrap();
-    config.set_str("user.name", "name").unwrap();
-    config.set_str("user.email", "email").unwrap();
+    let repo = Repository::init_opts(temp_dir.path(), &opts)?;
+    let mut config = repo.config()?;
+    config.set_str("user.name", "name")?;
+    config.set_str("user.email", "email")?;
 
-    if let Some(commits) = commits {
-        commits.iter().for_each(|commit| repo.add_commit(commit))
+    for commit_message in commits.unwrap_or_default() {
+        commit_on_head(&repo, commit_message)?;
     }
 
-    (temp_dir, repo)
+    Ok((temp_dir, repo))
+}
+
+/// Commit the index of a repository on top of HEAD.
+fn commit_on_head(repository: &Repository, commit_message: &str) -> Result<(), git2::Error> {
+    let id = repository.index()?.write_tree()?;
+    let tree = repository.find_tree(id)?;
+    let sig = repository.signature()?;
+
+    let parents = repository
+        .head()
+        .ok()
+        .and_then(|head| head.peel_to_commit().ok());
+    let parents = match &parents {
+        Some(commit) => vec![commit],
+        None => vec![],
+    };
+
+    repository.commit(Some("HEAD"), &sig, &sig, commit_message, &tree, &parents)?;
+    Ok(())
 }
 
 pub trait RepositoryTestExtensions {
     #[allow(dead_code)]
     fn add_commit(&self, commit_message: &str);
     #[allow(dead_code)]
+    fn add_commit_with_file(&self, commit_message: &str, file_path: &str);
+    #[allow(dead_code)]
     fn add_tag(&self, commit: Commit, tag_name: &str);
     #[allow(dead_code)]
-    fn find_commit_by_message(&self, commit_message: &str) -> Option<Commit>;
+    fn find_commit_by_message(&self, commit_message: &str) -> Option<Commit<'_>>;
 }
 
 impl RepositoryTestExtensions for Repository {
@@ -55,6 +104,22 @@ impl RepositoryTestExtensions for Repository {
         }
     }
 
+    #[doc(hidden)]
+    #[allow(dead_code)]
+    /// Write a file into the working directory of a given repository and commit it.
+    /// The file content changes on every call, so the commit always changes the file.
+    fn add_commit_with_file(&self, commit_message: &str, file_path: &str) {
+        let workdir = self.workdir().unwrap();
+        let full_path = workdir.join(file_path);
+        fs::create_dir_all(full_path.parent().unwrap()).unwrap();
+        fs::write(&full_path, commit_message).unwrap();
+
+        let mut index = self.index().unwrap();
+        index.add_path(Path::new(file_path)).unwrap();
+        index.write().unwrap();
+        self.add_commit(commit_message);
+    }
+
     #[doc(hidden)]
     #[allow(dead_code)]
     /// Add tag to a given commit.
@@ -70,14 +135,14 @@ impl RepositoryTestExtensions for Repository {
     /// Find a commit by its message
     /// ## Result
     /// The commit if it's found, None if it's not found
-    fn find_commit_by_message(&self, commit_message: &str) -> Option<Commit> {
+    fn find_commit_by_message(&self, commit_message: &str) -> Option<Commit<'_>> {
         let mut revwalk: Revwalk = self.revwalk().unwrap();
         revwalk.push_head().unwrap();
         revwalk.set_sorting(git2::Sort::TIME).unwrap();
 
         revwalk
             .map(|oid| self.find_commit(oid.unwrap()).unwrap())
-            .find(|commit| commit.message().unwrap().contains(commit_message))
+            .find(|commit| String::from_utf8_lossy(commit.message_bytes()).contains(commit_message))
     }
 }
 
@@ -91,3 +156,34 @@ impl fmt::Display for MockError {
 }
 
 impl Error for MockError {}
+
+#[cfg(test)]
+mod test_util_tests {
+    use crate::test_util::{try_repo_init, try_repo_init_in, RepositoryTestExtensions};
+    use std::path::Path;
+
+    #[test]
+    fn initializing_repository_with_commits() {
+        // When
+        let (_temp_dir, repository) = try_repo_init(Some(vec!["commit 1", "commit 2"])).unwrap();
+
+        // Then
+        assert!(repository.find_commit_by_message("commit 1").is_some());
+        assert!(repository.find_commit_by_message("commit 2").is_some());
+    }
+
+    #[test]
+    fn initializing_repository_in_missing_directory() {
+        // When
+        let result = try_repo_init_in(Path::new("/does/not/exist"), None);
+
+        // Then
+        let Err(error) = result else {
+            panic!("Expected an error, but got Ok");
+        };
+        assert!(
+            error.to_string().contains("/does/not/exist"),
+            "error message doesn't name the directory: {error}"
+        );
+    }
+}
@@ -1,17 +1,48 @@
 use cargo_semantic_release::test_util::repo_init;
 pub use cargo_semantic_release::test_util::RepositoryTestExtensions;
-use cargo_semantic_release::{Changes, SemanticVersionAction};
+use cargo_semantic_release::{Changes, ChangesError, SemanticVersionAction};
+use git2::Repository;
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+fn run_semantic_release(repository_path: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_cargo-semantic-release"))
+        .arg("semantic-release")
+        .args(args)
+        .current_dir(repository_path)
+        .output()
+        .unwrap()
+}
 
 #[test]
-fn empty_repo_raises_error() {
+fn empty_repo_keeps_semantic_version() {
     // Given
     let (_temp_dir, repository) = repo_init(None);
 
     // When
-    let result = Changes::from_repo(&repository);
+    let result = Changes::from_repo(&repository)
+        .unwrap()
+        .define_action_for_semantic_version();
 
     // Then
-    assert!(result.is_err(), "Expected Error, but got Ok");
+    assert_eq!(result, SemanticVersionAction::Keep);
+}
+
+#[test]
+fn empty_repo_has_no_commits() {
+    // Given
+    let (_temp_dir, repository) = repo_init(None);
+
+    // When
+    let result = Changes::try_from(&repository);
+
+    // Then
+    assert!(
+        matches!(result, Err(ChangesError::NoCommits)),
+        "Expected NoCommits error, but got {result:?}"
+    );
 }
 
 #[test]
@@ -85,3 +116,1277 @@ fn other_change_keeps_semantic_version() {
     // Then
     assert_eq!(result, SemanticVersionAction::Keep);
 }
+
+#[test]
+fn count_only_prints_single_line() {
+    // Given
+    let commit_messages = vec![
+        ":sparkles: introduce new feature",
+        ":green_heart: fix CI build",
+        ":memo: add or update documentation",
+        ":rocket: deploy",
+    ];
+    let (temp_dir, _repository) = repo_init(Some(commit_messages));
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--count-only"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "major=0 minor=1 patch=1 other=2\n"
+    );
+}
+
+#[test]
+fn count_only_conflicts_with_format() {
+    // Given
+    let (temp_dir, _repository) = repo_init(Some(vec![":tada: initial commit"]));
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--count-only", "--format", "text"]);
+
+    // Then
+    assert!(
+        !output.status.success(),
+        "Expected failure, but got {output:?}"
+    );
+}
+
+#[test]
+fn reading_commit_messages_from_stdin() {
+    // Given
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_cargo-semantic-release"))
+        .args(["semantic-release", "--stdin"])
+        .current_dir(temp_dir.path())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    // When
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(":sparkles: add feature\n\nplain commit\n🐛 fix bug\n".as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("Action for semantic version ➡️ increment minor version"),
+        "unexpected output: {stdout}"
+    );
+}
+
+#[test]
+fn tagged_head_is_already_released() {
+    // Given
+    let commit_messages = vec![":tada: initial commit", ":sparkles: introduce new feature"];
+    let (temp_dir, repository) = repo_init(Some(commit_messages));
+    let head = repository
+        .find_commit_by_message(":sparkles: introduce new feature")
+        .unwrap();
+    repository.add_tag(head, "v1.0.0");
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &[]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("Already at the latest release (v1.0.0)"),
+        "unexpected output: {stdout}"
+    );
+    assert!(
+        !stdout.contains("Changes in the repository"),
+        "unexpected output: {stdout}"
+    );
+}
+
+#[test]
+fn no_colors_with_color_never() {
+    // Given
+    let commit_messages = vec![":sparkles: introduce new feature", ":bug: fix bug"];
+    let (temp_dir, _repository) = repo_init(Some(commit_messages));
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--color", "never"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("minor 1\n"), "unexpected output: {stdout}");
+    assert!(
+        !stdout.contains('\x1b'),
+        "unexpected ANSI codes: {stdout:?}"
+    );
+}
+
+#[test]
+fn colored_categories_with_color_always() {
+    // Given
+    let commit_messages = vec![":sparkles: introduce new feature", ":bug: fix bug"];
+    let (temp_dir, _repository) = repo_init(Some(commit_messages));
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--color", "always"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains('\x1b'), "missing ANSI codes: {stdout:?}");
+}
+
+#[test]
+fn quiet_prints_only_the_action() {
+    // Given
+    let commit_messages = vec![":tada: initial commit", ":sparkles: introduce new feature"];
+    let (temp_dir, repository) = repo_init(Some(commit_messages));
+    let first_commit = repository
+        .find_commit_by_message(":tada: initial commit")
+        .unwrap();
+    repository.add_tag(first_commit, "v1.0.0");
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--quiet"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Action for semantic version ➡️ increment minor version\n"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn executing_command_with_next_version() {
+    // Given
+    let commit_messages = vec![":tada: initial commit", ":sparkles: introduce new feature"];
+    let (temp_dir, repository) = repo_init(Some(commit_messages));
+    let first_commit = repository
+        .find_commit_by_message(":tada: initial commit")
+        .unwrap();
+    repository.add_tag(first_commit, "v1.0.0");
+
+    // When
+    let output = run_semantic_release(
+        temp_dir.path(),
+        &["--quiet", "--exec", "echo release {version} as {action}"],
+    );
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("release 1.1.0 as minor"),
+        "unexpected output: {stdout}"
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn propagating_exit_status_of_command() {
+    // Given
+    let (temp_dir, _repository) = repo_init(Some(vec![":sparkles: introduce new feature"]));
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--exec", "exit 3"]);
+
+    // Then
+    assert_eq!(output.status.code(), Some(3));
+}
+
+#[test]
+fn explain_lists_intentions_of_each_category() {
+    // Given
+    let temp_dir = tempfile::tempdir().unwrap();
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--explain"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<Vec<&str>> = stdout
+        .lines()
+        .map(|line| line.split_whitespace().collect())
+        .collect();
+    for expected_line in [
+        [":boom:", "💥", "major"],
+        [":sparkles:", "✨", "minor"],
+        [":recycle:", "♻️", "patch"],
+        [":memo:", "📝", "other"],
+    ] {
+        assert!(
+            lines.contains(&expected_line.to_vec()),
+            "missing {expected_line:?} in output: {stdout}"
+        );
+    }
+}
+
+fn add_manifest(repository: &Repository, crate_dir: &str, version: &str) {
+    let workdir = repository.workdir().unwrap();
+    let manifest_path = Path::new(crate_dir).join("Cargo.toml");
+    fs::create_dir_all(workdir.join(crate_dir)).unwrap();
+    fs::write(
+        workdir.join(&manifest_path),
+        format!("[package]\nname = \"{crate_dir}\"\nversion = \"{version}\"\n"),
+    )
+    .unwrap();
+    let mut index = repository.index().unwrap();
+    index.add_path(&manifest_path).unwrap();
+    index.write().unwrap();
+}
+
+#[test]
+fn analyzing_member_of_workspace() {
+    // Given
+    let (temp_dir, repository) = repo_init(None);
+    add_manifest(&repository, "foo", "0.1.0");
+    add_manifest(&repository, "bar", "2.0.0");
+    repository.add_commit(":tada: initial commit");
+    repository.add_commit_with_file(":sparkles: add feature to foo", "foo/src/lib.rs");
+    repository.add_commit_with_file(":bug: fix bar", "bar/src/lib.rs");
+
+    // When
+    let foo_output = run_semantic_release(temp_dir.path(), &["--manifest-path", "foo"]);
+    let bar_output = run_semantic_release(temp_dir.path(), &["--manifest-path", "bar"]);
+
+    // Then
+    assert!(foo_output.status.success(), "{foo_output:?}");
+    let foo_stdout = String::from_utf8(foo_output.stdout).unwrap();
+    assert!(
+        foo_stdout.contains("Next version ➡️ 0.2.0") && !foo_stdout.contains("fix bar"),
+        "unexpected output: {foo_stdout}"
+    );
+    assert!(bar_output.status.success(), "{bar_output:?}");
+    let bar_stdout = String::from_utf8(bar_output.stdout).unwrap();
+    assert!(
+        bar_stdout.contains("Next version ➡️ 2.0.1") && !bar_stdout.contains("add feature"),
+        "unexpected output: {bar_stdout}"
+    );
+}
+
+#[test]
+fn resolving_path_filter_against_repository_root() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    repository.add_commit_with_file(":sparkles: add feature to foo", "foo/src/lib.rs");
+    repository.add_commit_with_file(":bug: fix bar", "bar/src/lib.rs");
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--path", "./foo/"]);
+    let outside_output = run_semantic_release(temp_dir.path(), &["--path", ".."]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("add feature to foo") && !stdout.contains("fix bar"),
+        "unexpected output: {stdout}"
+    );
+    assert!(!outside_output.status.success(), "{outside_output:?}");
+    let stderr = String::from_utf8(outside_output.stderr).unwrap();
+    assert!(
+        stderr.contains("is outside of the repository"),
+        "unexpected output: {stderr}"
+    );
+}
+
+#[test]
+fn warning_about_manifest_version_differing_from_tag() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    fs::write(
+        temp_dir.path().join("Cargo.toml"),
+        "[package]\nname = \"foo\"\nversion = \"1.1.0\"\n",
+    )
+    .unwrap();
+    let head = repository.head().unwrap().peel_to_commit().unwrap();
+    repository.add_tag(head, "v1.0.0");
+    repository.add_commit(":bug: fix bug");
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &[]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains(
+            "Warning: the version in Cargo.toml (1.1.0) differs from the latest version tag (1.0.0)"
+        ),
+        "unexpected output: {stderr}"
+    );
+}
+
+fn make_shallow(repository: &Repository) {
+    let head = repository.head().unwrap().peel_to_commit().unwrap();
+    fs::write(
+        repository.path().join("shallow"),
+        format!("{}\n", head.id()),
+    )
+    .unwrap();
+}
+
+#[test]
+fn warning_about_shallow_clone() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":sparkles: introduce new feature"]));
+    make_shallow(&repository);
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &[]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("shallow clone"),
+        "unexpected output: {stderr}"
+    );
+}
+
+#[test]
+fn denying_shallow_clone() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":sparkles: introduce new feature"]));
+    make_shallow(&repository);
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--deny-shallow"]);
+
+    // Then
+    assert!(!output.status.success(), "{output:?}");
+}
+
+#[test]
+fn reading_config_from_given_path() {
+    // Given
+    let (temp_dir, _repository) = repo_init(Some(vec!["💖 add sponsors"]));
+    let config_dir = tempfile::tempdir().unwrap();
+    let config_path = config_dir.path().join("release.toml");
+    fs::write(
+        &config_path,
+        "[[intentions]]\nshortcode = \":sparkling_heart:\"\nemoji = \"💖\"\ncategory = \"minor\"\n",
+    )
+    .unwrap();
+
+    // When
+    let output = run_semantic_release(
+        temp_dir.path(),
+        &["--config", config_path.to_str().unwrap()],
+    );
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("Action for semantic version ➡️ increment minor version"),
+        "unexpected output: {stdout}"
+    );
+}
+
+#[test]
+fn missing_config_is_an_error() {
+    // Given
+    let (temp_dir, _repository) = repo_init(Some(vec![":tada: initial commit"]));
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--config", "missing.toml"]);
+
+    // Then
+    assert!(!output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("missing.toml"),
+        "unexpected output: {stderr}"
+    );
+}
+
+#[test]
+fn running_directly_without_subcommand() {
+    // Given
+    let (temp_dir, _repository) = repo_init(Some(vec![":sparkles: introduce new feature"]));
+
+    // When
+    let version_output = Command::new(env!("CARGO_BIN_EXE_cargo-semantic-release"))
+        .arg("--version")
+        .output()
+        .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-semantic-release"))
+        .arg("--quiet")
+        .current_dir(temp_dir.path())
+        .output()
+        .unwrap();
+
+    // Then
+    assert!(version_output.status.success(), "{version_output:?}");
+    assert!(
+        String::from_utf8(version_output.stdout)
+            .unwrap()
+            .contains(env!("CARGO_PKG_VERSION")),
+        "version is missing from the output"
+    );
+    assert!(output.status.success(), "{output:?}");
+}
+
+#[test]
+fn running_through_cargo_with_subcommand() {
+    // When
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-semantic-release"))
+        .args(["semantic-release", "--version"])
+        .output()
+        .unwrap();
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    assert!(
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .contains(env!("CARGO_PKG_VERSION")),
+        "version is missing from the output"
+    );
+}
+
+#[test]
+fn tagging_next_version() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    let first_commit = repository
+        .find_commit_by_message(":tada: initial commit")
+        .unwrap();
+    repository.add_tag(first_commit, "v1.0.0");
+    repository.add_commit(":sparkles: introduce new feature");
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--quiet", "--tag"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let tagged_commit = repository
+        .revparse_single("v1.1.0")
+        .unwrap()
+        .peel_to_commit()
+        .unwrap();
+    assert_eq!(
+        tagged_commit.message(),
+        Some(":sparkles: introduce new feature")
+    );
+}
+
+#[test]
+fn refusing_to_tag_dirty_working_tree() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    let first_commit = repository
+        .find_commit_by_message(":tada: initial commit")
+        .unwrap();
+    repository.add_tag(first_commit, "v1.0.0");
+    repository.add_commit(":sparkles: introduce new feature");
+    fs::write(temp_dir.path().join("uncommitted.txt"), "work in progress").unwrap();
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--quiet", "--tag"]);
+
+    // Then
+    assert!(!output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("uncommitted changes"),
+        "unexpected output: {stderr}"
+    );
+    assert!(repository.revparse_single("v1.1.0").is_err());
+}
+
+#[test]
+fn printing_markdown_changelog() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    let first_commit = repository
+        .find_commit_by_message(":tada: initial commit")
+        .unwrap();
+    repository.add_tag(first_commit, "v1.0.0");
+    repository.add_commit(":sparkles: introduce new feature");
+    repository.add_commit(":bug: fix bug");
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--format", "markdown"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let headings: Vec<&str> = stdout
+        .lines()
+        .filter(|line| line.starts_with('#'))
+        .collect();
+    assert_eq!(headings, vec!["## v1.1.0", "### Features", "### Fixes"]);
+}
+
+#[test]
+fn printing_markdown_changelog_without_release() {
+    // Given
+    let (temp_dir, _repository) = repo_init(Some(vec![":memo: update the readme"]));
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--format", "markdown"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "No release necessary.\n"
+    );
+}
+
+#[test]
+fn linting_message_with_known_intention() {
+    // Given
+    let temp_dir = tempfile::tempdir().unwrap();
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--lint", ":sparkles: add linting"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Recognized minor intention: :sparkles: add linting\n"
+    );
+}
+
+#[test]
+fn linting_head_with_unknown_intention() {
+    // Given
+    let (temp_dir, _repository) = repo_init(Some(vec!["add linting"]));
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--lint"]);
+
+    // Then
+    assert!(!output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("Unrecognized intention: add linting"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn limiting_commits_per_category() {
+    // Given
+    let commit_messages = vec![
+        ":bug: fix bug 1",
+        ":bug: fix bug 2",
+        ":bug: fix bug 3",
+        ":bug: fix bug 4",
+        ":bug: fix bug 5",
+    ];
+    let (temp_dir, _repository) = repo_init(Some(commit_messages));
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--max-per-category", "2"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("patch 5\n"), "unexpected output: {stdout}");
+    assert_eq!(stdout.matches("    :bug: fix bug").count(), 2, "{stdout}");
+    assert!(
+        stdout.contains("    ...and 3 more\n"),
+        "unexpected output: {stdout}"
+    );
+}
+
+#[test]
+fn previewing_commit_message() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    let first_commit = repository
+        .find_commit_by_message(":tada: initial commit")
+        .unwrap();
+    repository.add_tag(first_commit, "v1.0.0");
+    repository.add_commit(":bug: fix bug");
+
+    // When
+    let output = run_semantic_release(
+        temp_dir.path(),
+        &["--preview-message", ":boom: remove the old API"],
+    );
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("Action for semantic version ➡️ increment major version"),
+        "unexpected output: {stdout}"
+    );
+    assert!(
+        stdout.contains("Next version ➡️ 2.0.0"),
+        "unexpected output: {stdout}"
+    );
+}
+
+#[test]
+fn rendering_intentions_as_shortcodes() {
+    // Given
+    let (temp_dir, _repository) = repo_init(Some(vec!["✨ introduce new feature"]));
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--emoji", "shortcode"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("    :sparkles: introduce new feature") && !stdout.contains('✨'),
+        "unexpected output: {stdout}"
+    );
+}
+
+#[test]
+fn strict_mode_fails_on_commit_without_intention() {
+    // Given
+    let commit_messages = vec![":sparkles: introduce new feature", "plain commit"];
+    let (temp_dir, _repository) = repo_init(Some(commit_messages));
+
+    // When
+    let strict_output = run_semantic_release(temp_dir.path(), &["--strict"]);
+    let output = run_semantic_release(temp_dir.path(), &[]);
+
+    // Then
+    assert!(!strict_output.status.success(), "{strict_output:?}");
+    let stderr = String::from_utf8(strict_output.stderr).unwrap();
+    assert!(
+        stderr.contains("plain commit"),
+        "unexpected output: {stderr}"
+    );
+    assert!(output.status.success(), "{output:?}");
+}
+
+#[test]
+fn strict_mode_ignores_commits_of_excluded_authors() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":sparkles: introduce new feature"]));
+    let head = repository.head().unwrap().peel_to_commit().unwrap();
+    let bot = git2::Signature::now("dependabot[bot]", "dependabot[bot]@example.com").unwrap();
+    repository
+        .commit(
+            Some("HEAD"),
+            &bot,
+            &bot,
+            "Bump serde from 1.0.0 to 1.0.1",
+            &head.tree().unwrap(),
+            &[&head],
+        )
+        .unwrap();
+
+    // When
+    let output = run_semantic_release(
+        temp_dir.path(),
+        &["--strict", "--exclude-author", "dependabot[bot]"],
+    );
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+}
+
+#[test]
+fn rejecting_invalid_date_format() {
+    // Given
+    let (temp_dir, _repository) = repo_init(Some(vec![":sparkles: introduce new feature"]));
+
+    // When
+    let output = run_semantic_release(
+        temp_dir.path(),
+        &["--format", "markdown", "--date-format", "%Q"],
+    );
+
+    // Then
+    assert!(!output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("invalid date format"),
+        "unexpected output: {stderr}"
+    );
+}
+
+#[test]
+fn committing_release_of_next_version() {
+    // Given
+    let (temp_dir, repository) = repo_init(None);
+    add_manifest(&repository, "foo", "1.0.0");
+    fs::write(
+        temp_dir.path().join("Cargo.lock"),
+        "version = 4\n\n[[package]]\nname = \"foo\"\nversion = \"1.0.0\"\n",
+    )
+    .unwrap();
+    let mut index = repository.index().unwrap();
+    index.add_path(Path::new("Cargo.lock")).unwrap();
+    index.write().unwrap();
+    repository.add_commit(":tada: initial commit");
+    repository.add_commit_with_file(":sparkles: add feature", "foo/src/lib.rs");
+
+    // When
+    let output = run_semantic_release(
+        temp_dir.path(),
+        &["--manifest-path", "foo", "--commit", "--tag"],
+    );
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let manifest = fs::read_to_string(temp_dir.path().join("foo/Cargo.toml")).unwrap();
+    assert!(
+        manifest.contains("version = \"1.1.0\""),
+        "unexpected manifest: {manifest}"
+    );
+    let head = repository.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.message(), Some(":bookmark: release v1.1.0"));
+    let tree_manifest = head
+        .tree()
+        .unwrap()
+        .get_path(Path::new("foo/Cargo.toml"))
+        .unwrap()
+        .to_object(&repository)
+        .unwrap()
+        .peel_to_blob()
+        .unwrap();
+    assert_eq!(tree_manifest.content(), manifest.as_bytes());
+    let lockfile = fs::read_to_string(temp_dir.path().join("Cargo.lock")).unwrap();
+    assert!(
+        lockfile.contains("version = \"1.1.0\""),
+        "unexpected lock file: {lockfile}"
+    );
+    let tree_lockfile = head
+        .tree()
+        .unwrap()
+        .get_path(Path::new("Cargo.lock"))
+        .unwrap()
+        .to_object(&repository)
+        .unwrap()
+        .peel_to_blob()
+        .unwrap();
+    assert_eq!(tree_lockfile.content(), lockfile.as_bytes());
+    let tag_commit = repository
+        .revparse_single("v1.1.0")
+        .unwrap()
+        .peel_to_commit()
+        .unwrap();
+    assert_eq!(tag_commit.id(), head.id());
+}
+
+#[test]
+fn refusing_release_on_detached_head() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":tada: initial commit")
+            .unwrap(),
+        "v1.0.0",
+    );
+    repository.add_commit(":sparkles: add feature");
+    let head = repository.head().unwrap().peel_to_commit().unwrap();
+    repository.set_head_detached(head.id()).unwrap();
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--tag"]);
+
+    // Then
+    assert!(!output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("HEAD is detached"),
+        "unexpected output: {stderr}"
+    );
+    assert!(
+        repository.revparse_single("v1.1.0").is_err(),
+        "Expected no tag to be created"
+    );
+}
+
+#[test]
+fn refusing_release_commit_without_changes() {
+    // Given
+    let (temp_dir, repository) = repo_init(None);
+    add_manifest(&repository, "foo", "1.0.0");
+    repository.add_commit(":memo: add readme");
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--manifest-path", "foo", "--commit"]);
+
+    // Then
+    assert!(!output.status.success(), "{output:?}");
+    let manifest = fs::read_to_string(temp_dir.path().join("foo/Cargo.toml")).unwrap();
+    assert!(
+        manifest.contains("version = \"1.0.0\""),
+        "unexpected manifest: {manifest}"
+    );
+}
+
+#[test]
+fn comparing_against_tag_reachable_from_reference() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    let initial_commit = repository
+        .find_commit_by_message(":tada: initial commit")
+        .unwrap();
+    repository.add_tag(initial_commit.clone(), "v1.0.0");
+    repository
+        .branch("release/1.x", &initial_commit, false)
+        .unwrap();
+    repository.set_head("refs/heads/release/1.x").unwrap();
+    repository.add_commit(":bug: fix first bug");
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":bug: fix first bug")
+            .unwrap(),
+        "v1.0.1",
+    );
+    repository.add_commit(":bug: fix second bug");
+    repository.set_head("refs/heads/main").unwrap();
+    repository.add_commit(":sparkles: add feature");
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":sparkles: add feature")
+            .unwrap(),
+        "v1.1.0",
+    );
+
+    // When
+    let output = run_semantic_release(
+        temp_dir.path(),
+        &[
+            "--ref",
+            "release/1.x",
+            "--reachable-tag",
+            "--color",
+            "never",
+        ],
+    );
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("Next version ➡️ 1.0.2") && !stdout.contains("fix first bug"),
+        "unexpected output: {stdout}"
+    );
+}
+
+#[test]
+fn analyzing_range_between_tag_and_head() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":boom: remove the old API"]));
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":boom: remove the old API")
+            .unwrap(),
+        "v1.0.0",
+    );
+    repository.add_commit(":sparkles: add feature");
+    repository.add_commit(":bug: fix bug");
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--range", "v1.0.0..HEAD~1"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains("add feature"),
+        "unexpected output: {stdout}"
+    );
+    assert!(!stdout.contains("fix bug"), "unexpected output: {stdout}");
+    assert!(
+        !stdout.contains("remove the old API"),
+        "unexpected output: {stdout}"
+    );
+}
+
+#[test]
+fn rejecting_three_dot_range() {
+    // Given
+    let (temp_dir, _repository) = repo_init(Some(vec![":sparkles: add feature", ":bug: fix bug"]));
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--range", "HEAD~1...HEAD"]);
+
+    // Then
+    assert!(!output.status.success(), "{output:?}");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("three-dot"), "unexpected error: {stderr}");
+}
+
+#[test]
+fn suggesting_initial_version_without_version_tags() {
+    // Given
+    let (temp_dir, _repository) = repo_init(Some(vec![":tada: initial commit"]));
+
+    // When
+    let default_output = run_semantic_release(temp_dir.path(), &[]);
+    let configured_output = run_semantic_release(temp_dir.path(), &["--initial-version", "1.0.0"]);
+
+    // Then
+    assert!(default_output.status.success(), "{default_output:?}");
+    let default_stdout = String::from_utf8(default_output.stdout).unwrap();
+    assert!(
+        default_stdout.contains("No version tags yet, initial version ➡️ 0.1.0"),
+        "unexpected output: {default_stdout}"
+    );
+    assert!(
+        !default_stdout.contains("Next version"),
+        "unexpected output: {default_stdout}"
+    );
+    let configured_stdout = String::from_utf8(configured_output.stdout).unwrap();
+    assert!(
+        configured_stdout.contains("No version tags yet, initial version ➡️ 1.0.0"),
+        "unexpected output: {configured_stdout}"
+    );
+}
+
+#[test]
+fn skipping_commits_with_release_marker() {
+    // Given
+    let commit_messages = vec![":bug: fix bug", ":sparkles: tweak CI [skip release]"];
+    let (temp_dir, _repository) = repo_init(Some(commit_messages));
+
+    // When
+    let default_output = run_semantic_release(temp_dir.path(), &["--quiet"]);
+    let custom_output =
+        run_semantic_release(temp_dir.path(), &["--quiet", "--skip-marker", "[no bump]"]);
+
+    // Then
+    assert_eq!(
+        String::from_utf8(default_output.stdout).unwrap(),
+        "Action for semantic version ➡️ increment patch version\n"
+    );
+    assert_eq!(
+        String::from_utf8(custom_output.stdout).unwrap(),
+        "Action for semantic version ➡️ increment minor version\n"
+    );
+}
+
+#[test]
+fn bumping_calendar_version_to_current_month() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit", ":bug: fix bug"]));
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":tada: initial commit")
+            .unwrap(),
+        "2024.05.0",
+    );
+    repository.add_tag(
+        repository.find_commit_by_message(":bug: fix bug").unwrap(),
+        "2024.12.1",
+    );
+    repository.add_commit(":sparkles: add feature");
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--scheme", "calver"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap();
+    let today = chrono::DateTime::from_timestamp(now.as_secs() as i64, 0).unwrap();
+    let expected = format!(
+        "Next version ➡️ {}.{:02}.0",
+        chrono::Datelike::year(&today),
+        chrono::Datelike::month(&today)
+    );
+    assert!(stdout.contains(&expected), "unexpected output: {stdout}");
+    assert!(!stdout.contains("fix bug"), "unexpected output: {stdout}");
+}
+
+#[test]
+fn porcelain_prints_stable_line() {
+    // Given
+    let commit_messages = vec![
+        ":tada: initial commit",
+        ":sparkles: add feature",
+        ":bug: fix bug",
+        ":memo: update the readme",
+    ];
+    let (temp_dir, repository) = repo_init(Some(commit_messages));
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":tada: initial commit")
+            .unwrap(),
+        "v1.0.0",
+    );
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--porcelain"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "action=minor\tnext=1.1.0\tcounts=0,1,1,1\n"
+    );
+}
+
+#[test]
+fn strict_zero_ver_increments_minor_on_breaking_change() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":tada: initial commit")
+            .unwrap(),
+        "v0.3.0",
+    );
+    repository.add_commit(":boom: remove the old API");
+
+    // When
+    let strict_output = run_semantic_release(temp_dir.path(), &["--zero-ver", "strict"]);
+    let loose_output = run_semantic_release(temp_dir.path(), &[]);
+
+    // Then
+    let strict_stdout = String::from_utf8(strict_output.stdout).unwrap();
+    assert!(
+        strict_stdout.contains("Next version ➡️ 0.4.0"),
+        "unexpected output: {strict_stdout}"
+    );
+    let loose_stdout = String::from_utf8(loose_output.stdout).unwrap();
+    assert!(
+        loose_stdout.contains("Next version ➡️ 1.0.0"),
+        "unexpected output: {loose_stdout}"
+    );
+}
+
+#[test]
+fn generating_changelog_of_all_tags() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":tada: initial commit")
+            .unwrap(),
+        "v1.0.0",
+    );
+    repository.add_commit(":sparkles: add feature");
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":sparkles: add feature")
+            .unwrap(),
+        "v1.1.0",
+    );
+    repository.add_commit(":bug: fix bug");
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--all-tags"]);
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let sections: Vec<&str> = stdout
+        .lines()
+        .filter(|line| line.starts_with("## "))
+        .collect();
+    assert_eq!(sections, ["## Unreleased", "## v1.1.0", "## v1.0.0"]);
+    let unreleased =
+        &stdout[stdout.find("## Unreleased").unwrap()..stdout.find("## v1.1.0").unwrap()];
+    assert!(
+        unreleased.contains(":bug: fix bug"),
+        "unexpected output: {stdout}"
+    );
+    let first_release = &stdout[stdout.find("## v1.0.0").unwrap()..];
+    assert!(
+        first_release.contains(":tada: initial commit"),
+        "unexpected output: {stdout}"
+    );
+}
+
+#[test]
+fn writing_github_output() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":tada: initial commit")
+            .unwrap(),
+        "v1.0.0",
+    );
+    repository.add_commit(":sparkles: add feature");
+    let github_output = temp_dir.path().join("github_output");
+    fs::write(&github_output, "existing=output\n").unwrap();
+
+    // When
+    let output = Command::new(env!("CARGO_BIN_EXE_cargo-semantic-release"))
+        .args(["semantic-release", "--github-output"])
+        .env("GITHUB_OUTPUT", &github_output)
+        .current_dir(temp_dir.path())
+        .output()
+        .unwrap();
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let content = fs::read_to_string(&github_output).unwrap();
+    let expected_start = "existing=output\naction=minor\nnext-version=1.1.0\nchanged=true\nchangelog<<EOF\n## v1.1.0\n";
+    assert!(
+        content.starts_with(expected_start),
+        "unexpected output: {content}"
+    );
+    assert!(
+        content.contains("\n- :sparkles: add feature (") && content.ends_with(")\nEOF\n"),
+        "unexpected output: {content}"
+    );
+}
+
+#[test]
+fn hiding_other_changes() {
+    // Given
+    let commit_messages = vec![":tada: initial commit", ":memo: update the readme"];
+    let (temp_dir, repository) = repo_init(Some(commit_messages));
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":tada: initial commit")
+            .unwrap(),
+        "v1.0.0",
+    );
+
+    // When
+    let text_output = run_semantic_release(
+        temp_dir.path(),
+        &["--hide-other", "--docs-trigger-patch", "--color", "never"],
+    );
+    let markdown_output = run_semantic_release(
+        temp_dir.path(),
+        &[
+            "--hide-other",
+            "--docs-trigger-patch",
+            "--format",
+            "markdown",
+        ],
+    );
+
+    // Then
+    let text_stdout = String::from_utf8(text_output.stdout).unwrap();
+    assert!(
+        text_stdout.contains("Action for semantic version ➡️ increment patch version"),
+        "unexpected output: {text_stdout}"
+    );
+    assert!(
+        !text_stdout.contains("other") && !text_stdout.contains("    :memo: update the readme"),
+        "unexpected output: {text_stdout}"
+    );
+    let markdown_stdout = String::from_utf8(markdown_output.stdout).unwrap();
+    assert_eq!(markdown_stdout, "## v1.0.1\n");
+}
+
+#[test]
+fn hiding_other_changes_after_collapsing_reverts_in_all_tags() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":tada: initial commit")
+            .unwrap(),
+        "v1.0.0",
+    );
+    repository.add_commit(":sparkles: add feature");
+    let feature_commit = repository
+        .find_commit_by_message(":sparkles: add feature")
+        .unwrap();
+    repository.add_commit(&format!(
+        ":rewind: revert add feature\n\nThis reverts commit {}.",
+        feature_commit.id()
+    ));
+    repository.add_commit(":bug: fix bug");
+
+    // When
+    let output = run_semantic_release(
+        temp_dir.path(),
+        &["--all-tags", "--hide-other", "--collapse-reverts"],
+    );
+
+    // Then
+    assert!(output.status.success(), "{output:?}");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.contains(":bug: fix bug"),
+        "unexpected output: {stdout}"
+    );
+    assert!(
+        !stdout.contains(":sparkles: add feature"),
+        "reverted commit is in the changelog: {stdout}"
+    );
+}
+
+#[test]
+fn naming_versions_with_tag_prefix() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":tada: initial commit")
+            .unwrap(),
+        "release-1.0.0",
+    );
+
+    // When
+    let released_output = run_semantic_release(
+        temp_dir.path(),
+        &["--tag-prefix", "release-", "--color", "never"],
+    );
+    repository.add_commit(":bug: fix bug");
+    let markdown_output = run_semantic_release(
+        temp_dir.path(),
+        &["--tag-prefix", "release-", "--format", "markdown"],
+    );
+
+    // Then
+    let released_stdout = String::from_utf8(released_output.stdout).unwrap();
+    assert!(
+        released_stdout.contains("Already at the latest release (release-1.0.0)"),
+        "unexpected output: {released_stdout}"
+    );
+    let markdown_stdout = String::from_utf8(markdown_output.stdout).unwrap();
+    assert!(
+        markdown_stdout.starts_with("## release-1.0.1\n"),
+        "unexpected output: {markdown_stdout}"
+    );
+}
+
+#[test]
+fn thanking_contributors_in_changelog() {
+    // Given
+    let (temp_dir, repository) = repo_init(Some(vec![":tada: initial commit"]));
+    repository.add_tag(
+        repository
+            .find_commit_by_message(":tada: initial commit")
+            .unwrap(),
+        "v1.0.0",
+    );
+    repository.add_commit(":bug: fix bug");
+
+    // When
+    let output = run_semantic_release(temp_dir.path(), &["--thanks", "--format", "markdown"]);
+    let history_output = run_semantic_release(temp_dir.path(), &["--thanks", "--all-tags"]);
+
+    // Then
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.ends_with("\n### Contributors\n\n- name <email>\n"),
+        "unexpected output: {stdout}"
+    );
+    let history_stdout = String::from_utf8(history_output.stdout).unwrap();
+    assert_eq!(
+        history_stdout.matches("### Contributors").count(),
+        2,
+        "unexpected output: {history_stdout}"
+    );
+}